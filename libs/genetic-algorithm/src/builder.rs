@@ -0,0 +1,175 @@
+use crate::*;
+
+// Puts a `GeneticAlgorithm` together one operator at a time, with the
+// selection method picked at runtime rather than fixed by its type
+pub struct GeneticAlgorithmBuilder<G = f32> {
+    selection_method: Option<AnySelection>,
+    crossover_method: Option<Box<dyn CrossoverMethod<G>>>,
+    mutation_method: Option<Box<dyn MutationMethod<G>>>,
+    elitism: Elitism,
+    fitness_transforms: Vec<Box<dyn FitnessTransform>>,
+    hall_of_fame: Option<HallOfFame<G>>,
+    history: bool,
+    observers: Vec<Box<dyn Observer<G>>>,
+}
+
+impl<G> GeneticAlgorithmBuilder<G>
+where
+    G: Gene,
+{
+    pub fn new() -> Self {
+        Self {
+            selection_method: None,
+            crossover_method: None,
+            mutation_method: None,
+            elitism: Elitism::default(),
+            fitness_transforms: Vec::new(),
+            hall_of_fame: None,
+            history: false,
+            observers: Vec::new(),
+        }
+    }
+
+    pub fn with_selection(mut self, selection_method: impl Into<AnySelection>) -> Self {
+        self.selection_method = Some(selection_method.into());
+        self
+    }
+
+    pub fn with_crossover(mut self, crossover_method: impl CrossoverMethod<G> + 'static) -> Self {
+        self.crossover_method = Some(Box::new(crossover_method));
+        self
+    }
+
+    pub fn with_mutation(mut self, mutation_method: impl MutationMethod<G> + 'static) -> Self {
+        self.mutation_method = Some(Box::new(mutation_method));
+        self
+    }
+
+    pub fn with_elitism(mut self, elitism: Elitism) -> Self {
+        elitism.validate();
+        self.elitism = elitism;
+        self
+    }
+
+    pub fn with_fitness_transform(mut self, transform: impl FitnessTransform + 'static) -> Self {
+        self.fitness_transforms.push(Box::new(transform));
+        self
+    }
+
+    pub fn with_hall_of_fame(mut self, hall_of_fame: HallOfFame<G>) -> Self {
+        self.hall_of_fame = Some(hall_of_fame);
+        self
+    }
+
+    pub fn with_history(mut self) -> Self {
+        self.history = true;
+        self
+    }
+
+    pub fn with_observer(mut self, observer: impl Observer<G> + 'static) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    pub fn build(self) -> GeneticAlgorithm<AnySelection, G> {
+        GeneticAlgorithm {
+            selection_method: self.selection_method.expect("got no selection method"),
+            crossover_method: self.crossover_method.expect("got no crossover method"),
+            mutation_method: self.mutation_method.expect("got no mutation method"),
+            elitism: self.elitism,
+            fitness_transforms: self.fitness_transforms,
+            hall_of_fame: self.hall_of_fame,
+            history: self.history.then(RunHistory::new),
+            observers: self.observers,
+            generation: 0,
+            config: None,
+        }
+    }
+}
+
+impl GeneticAlgorithmBuilder {
+    pub fn from_config(config: &GeneticAlgorithmConfig) -> Result<Self, ConfigError> {
+        check_elitism(config.elitism)?;
+
+        Ok(Self {
+            selection_method: Some(config.selection.build()?),
+            crossover_method: Some(config.crossover.build()?),
+            mutation_method: Some(config.mutation.build()?),
+            elitism: config.elitism,
+            fitness_transforms: config
+                .fitness_transforms
+                .iter()
+                .map(TransformConfig::build)
+                .collect::<Result<_, _>>()?,
+            hall_of_fame: config
+                .hall_of_fame
+                .as_ref()
+                .map(HallOfFameConfig::build)
+                .transpose()?,
+            history: config.history,
+            observers: Vec::new(),
+        })
+    }
+}
+
+impl<G> Default for GeneticAlgorithmBuilder<G>
+where
+    G: Gene,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn population() -> Vec<TestIndividual> {
+        (0..6)
+            .map(|n| TestIndividual::create(vec![n as f32, 1.0, 2.0].into_iter().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut expected = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            GaussianMethod::new(0.5, 0.5),
+        )
+        .with_elitism(Elitism::Count(1))
+        .with_hall_of_fame(HallOfFame::new(2))
+        .with_history();
+        let mut actual = GeneticAlgorithmBuilder::new()
+            .with_selection(RouletteWheelSelection::new())
+            .with_crossover(UniformCrossover::new())
+            .with_mutation(GaussianMethod::new(0.5, 0.5))
+            .with_elitism(Elitism::Count(1))
+            .with_hall_of_fame(HallOfFame::new(2))
+            .with_history()
+            .build();
+
+        let (expected_population, _) = expected.evolve(&mut rng.clone(), &population());
+        let (actual_population, _) = actual.evolve(&mut rng, &population());
+
+        assert_eq!(actual_population, expected_population);
+        assert_eq!(
+            actual.hall_of_fame().unwrap().champions(),
+            expected.hall_of_fame().unwrap().champions()
+        );
+        assert_eq!(actual.history().unwrap().len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "got no mutation method")]
+    fn missing_operator() {
+        GeneticAlgorithmBuilder::<f32>::new()
+            .with_selection(TournamentSelection::new(2))
+            .with_crossover(UniformCrossover::new())
+            .build();
+    }
+}
@@ -0,0 +1,236 @@
+use crate::*;
+
+// Snapshot of a run between two generations: the population about to be
+// evaluated, the random number generator and whatever the algorithm has
+// picked up along the way (adapted mutation, cooled down transforms, hall of
+// fame, history). The operators themselves aren't part of it - resume into an
+// algorithm set up the same way as the one the checkpoint was taken from.
+// For algorithms built from a `GeneticAlgorithmConfig` the config comes along
+// and gets checked on resume; hand-built ones can't be told apart.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Checkpoint<R, G = f32> {
+    generation: usize,
+    population: Vec<Chromosome<G>>,
+    rng: R,
+    mutation_state: Option<serde_json::Value>,
+    transform_states: Vec<Option<serde_json::Value>>,
+    hall_of_fame: Option<HallOfFame<G>>,
+    history: Option<RunHistory<G>>,
+    config: Option<GeneticAlgorithmConfig>,
+}
+
+impl<R, G> Checkpoint<R, G> {
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn population(&self) -> &[Chromosome<G>] {
+        &self.population
+    }
+
+    // Setup of the algorithm the checkpoint was taken from, to build the one
+    // to resume into
+    pub fn config(&self) -> Option<&GeneticAlgorithmConfig> {
+        self.config.as_ref()
+    }
+}
+
+impl<S, G> GeneticAlgorithm<S, G>
+where
+    S: SelectionMethod,
+    G: Gene,
+{
+    // Take it from the population `evolve` returned, before evaluating it:
+    // only the chromosomes are saved, fitness gets evaluated anew on resume
+    pub fn checkpoint<I, R>(&self, rng: &R, population: &[I]) -> Checkpoint<R, G>
+    where
+        I: Individual<Gene = G>,
+        R: Clone,
+    {
+        Checkpoint {
+            generation: self.generation,
+            population: population
+                .iter()
+                .map(|individual| individual.chromosome().clone())
+                .collect(),
+            rng: rng.clone(),
+            mutation_state: self.mutation_method.state(),
+            transform_states: self
+                .fitness_transforms
+                .iter()
+                .map(|transform| transform.state())
+                .collect(),
+            hall_of_fame: self.hall_of_fame.clone(),
+            history: self.history.clone(),
+            config: self.config.clone(),
+        }
+    }
+
+    // Returns the generator and the population to carry on with
+    pub fn resume<I, R>(&mut self, checkpoint: Checkpoint<R, G>) -> (R, Vec<I>)
+    where
+        I: Individual<Gene = G>,
+    {
+        assert!(
+            checkpoint.config == self.config,
+            "checkpoint was taken with a different setup, got {:?} instead of {:?}",
+            checkpoint.config,
+            self.config
+        );
+        assert_eq!(
+            checkpoint.transform_states.len(),
+            self.fitness_transforms.len(),
+            "checkpoint was taken with different fitness transforms"
+        );
+
+        self.generation = checkpoint.generation;
+        if let Some(state) = checkpoint.mutation_state {
+            self.mutation_method.restore_state(state);
+        }
+        for (transform, state) in self
+            .fitness_transforms
+            .iter_mut()
+            .zip(checkpoint.transform_states)
+        {
+            if let Some(state) = state {
+                transform.restore_state(state);
+            }
+        }
+        self.hall_of_fame = checkpoint.hall_of_fame;
+        self.history = checkpoint.history;
+
+        let population = checkpoint.population.into_iter().map(I::create).collect();
+        (checkpoint.rng, population)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    fn ga() -> GeneticAlgorithm<RouletteWheelSelection> {
+        GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            AdaptiveMutation::new(0.5, 0.5).with_window(3),
+        )
+        .with_fitness_transform(BoltzmannScaling::with_cooling(10.0, 0.9, 1.0))
+        .with_hall_of_fame(HallOfFame::new(3).with_reinjection(1))
+    }
+
+    fn population(rng: &mut ChaCha8Rng) -> Vec<TestIndividual> {
+        (0..10)
+            .map(|_| TestIndividual::create((0..3).map(|_| rng.gen::<f32>()).collect()))
+            .collect()
+    }
+
+    fn evolve(
+        ga: &mut GeneticAlgorithm<RouletteWheelSelection>,
+        rng: &mut ChaCha8Rng,
+        mut population: Vec<TestIndividual>,
+        generations: usize,
+    ) -> Vec<TestIndividual> {
+        for _ in 0..generations {
+            (population, _) = ga.evolve(rng, &population);
+        }
+        population
+    }
+
+    #[test]
+    fn resumes_bit_for_bit() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population(&mut rng);
+
+        let mut continuous = ga();
+        let expected = evolve(&mut continuous, &mut rng.clone(), population.clone(), 20);
+
+        let mut interrupted = ga();
+        let population = evolve(&mut interrupted, &mut rng, population, 10);
+        let json = serde_json::to_string(&interrupted.checkpoint(&rng, &population)).unwrap();
+        drop(interrupted);
+
+        let checkpoint: Checkpoint<ChaCha8Rng> = serde_json::from_str(&json).unwrap();
+        assert_eq!(checkpoint.generation(), 10);
+        let mut resumed = ga();
+        let (mut rng, population) = resumed.resume(checkpoint);
+        let actual = evolve(&mut resumed, &mut rng, population, 10);
+
+        assert_eq!(actual, expected);
+        assert_eq!(resumed.generation(), 20);
+        assert_eq!(
+            resumed.hall_of_fame().unwrap().champions(),
+            continuous.hall_of_fame().unwrap().champions()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_transforms() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population(&mut rng);
+        let checkpoint = ga().checkpoint(&rng, &population);
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            AdaptiveMutation::new(0.5, 0.5),
+        );
+        ga.resume::<TestIndividual, _>(checkpoint);
+    }
+
+    mod config {
+        use super::*;
+
+        fn config(chance: f32) -> GeneticAlgorithmConfig {
+            GeneticAlgorithmConfig::new(
+                SelectionConfig::RouletteWheel,
+                CrossoverConfig::Uniform,
+                MutationConfig::Gaussian { chance, coeff: 0.5 },
+            )
+        }
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let population = population(&mut rng);
+            let json =
+                serde_json::to_string(&config(0.5).build().unwrap().checkpoint(&rng, &population))
+                    .unwrap();
+
+            let checkpoint: Checkpoint<ChaCha8Rng> = serde_json::from_str(&json).unwrap();
+            assert_eq!(checkpoint.config(), Some(&config(0.5)));
+            let mut resumed = checkpoint.config().unwrap().build().unwrap();
+            resumed.resume::<TestIndividual, _>(checkpoint);
+        }
+
+        #[test]
+        #[should_panic(expected = "checkpoint was taken with a different setup")]
+        fn different() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let population = population(&mut rng);
+            let checkpoint = config(0.5).build().unwrap().checkpoint(&rng, &population);
+
+            config(0.1)
+                .build()
+                .unwrap()
+                .resume::<TestIndividual, _>(checkpoint);
+        }
+
+        #[test]
+        #[should_panic(expected = "checkpoint was taken with a different setup")]
+        fn hand_built() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let population = population(&mut rng);
+            let checkpoint = config(0.5).build().unwrap().checkpoint(&rng, &population);
+
+            GeneticAlgorithm::new(
+                AnySelection::from(RouletteWheelSelection::new()),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.1, 0.5),
+            )
+            .resume::<TestIndividual, _>(checkpoint);
+        }
+    }
+}
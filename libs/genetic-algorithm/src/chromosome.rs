@@ -1,53 +1,158 @@
+use crate::*;
 use std::iter::{FromIterator, IntoIterator};
 use std::ops::Index;
 
 #[derive(Clone, Debug)]
-pub struct Chromosome {
-    genes: Vec<f32>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawChromosome<G>"))]
+pub struct Chromosome<G = f32> {
+    genes: Vec<G>,
+    // Optional strategy parameters for self-adaptive evolution, one mutation
+    // step size per gene. Empty unless set explicitly; operators which don't
+    // know about them drop them.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    strategy: Vec<f32>,
 }
 
-impl Chromosome {
+impl<G> Chromosome<G> {
     pub fn len(&self) -> usize {
         self.genes.len()
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &f32> {
+    pub fn is_empty(&self) -> bool {
+        self.genes.is_empty()
+    }
+
+    pub fn with_strategy(mut self, strategy: Vec<f32>) -> Self {
+        assert!(strategy.is_empty() || strategy.len() == self.genes.len());
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn strategy(&self) -> &[f32] {
+        &self.strategy
+    }
+
+    pub fn has_strategy(&self) -> bool {
+        !self.strategy.is_empty()
+    }
+
+    // Each gene paired with its step size
+    pub fn iter_mut_with_strategy(&mut self) -> impl Iterator<Item = (&mut G, &mut f32)> {
+        assert!(self.has_strategy(), "chromosome has no strategy parameters");
+        self.genes.iter_mut().zip(self.strategy.iter_mut())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &G> {
         self.genes.iter()
     }
 
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut G> {
         self.genes.iter_mut()
     }
+
+    pub fn genes(&self) -> &[G] {
+        &self.genes
+    }
+
+    pub fn genes_mut(&mut self) -> &mut [G] {
+        &mut self.genes
+    }
+}
+
+// What gets deserialized before the strategy is checked against the genes
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawChromosome<G> {
+    genes: Vec<G>,
+    #[serde(default)]
+    strategy: Vec<f32>,
 }
 
-impl Index<usize> for Chromosome {
-    type Output = f32;
+#[cfg(feature = "serde")]
+impl<G> TryFrom<RawChromosome<G>> for Chromosome<G> {
+    type Error = String;
+
+    fn try_from(raw: RawChromosome<G>) -> Result<Self, Self::Error> {
+        if !raw.strategy.is_empty() && raw.strategy.len() != raw.genes.len() {
+            return Err(format!(
+                "expected a strategy parameter for each of the {} genes, got {}",
+                raw.genes.len(),
+                raw.strategy.len()
+            ));
+        }
+
+        Ok(Self {
+            genes: raw.genes,
+            strategy: raw.strategy,
+        })
+    }
+}
+
+impl<G> Chromosome<G>
+where
+    G: Gene,
+{
+    // Euclidean distance between the genes of two chromosomes
+    pub fn distance(&self, other: &Self) -> f32 {
+        assert_eq!(self.len(), other.len());
+
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| (a.as_f64() - b.as_f64()).powi(2))
+            .sum::<f64>()
+            .sqrt() as f32
+    }
+}
+
+// Random range of at least two genes of a chromosome of the given length
+pub(crate) fn segment(rng: &mut dyn RngCore, len: usize) -> (usize, usize) {
+    let start = rng.gen_range(0..len - 1);
+    let end = rng.gen_range(start + 2..=len);
+    (start, end)
+}
+
+impl<G> Index<usize> for Chromosome<G> {
+    type Output = G;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.genes[index]
     }
 }
 
-impl FromIterator<f32> for Chromosome {
-    fn from_iter<T: IntoIterator<Item = f32>>(iter: T) -> Self {
+impl<G> FromIterator<G> for Chromosome<G> {
+    fn from_iter<T: IntoIterator<Item = G>>(iter: T) -> Self {
         Self {
             genes: iter.into_iter().collect(),
+            strategy: Vec::new(),
         }
     }
 }
 
-impl IntoIterator for Chromosome {
-    type Item = f32;
-    type IntoIter = std::vec::IntoIter<f32>;
+impl<G> IntoIterator for Chromosome<G> {
+    type Item = G;
+    type IntoIter = std::vec::IntoIter<G>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.genes.into_iter()
     }
 }
 
-impl PartialEq for Chromosome {
+impl<G> PartialEq for Chromosome<G>
+where
+    G: Gene,
+{
     fn eq(&self, other: &Self) -> bool {
-        approx::relative_eq!(self.genes.as_slice(), other.genes.as_slice())
+        self.genes.len() == other.genes.len()
+            && self
+                .genes
+                .iter()
+                .zip(&other.genes)
+                .all(|(a, b)| a.gene_eq(b))
+            && approx::relative_eq!(self.strategy.as_slice(), other.strategy.as_slice())
     }
 }
 
@@ -58,6 +163,7 @@ mod tests {
     fn chromosome() -> Chromosome {
         Chromosome {
             genes: vec![3.0, 1.0, 2.0],
+            strategy: Vec::new(),
         }
     }
 
@@ -70,6 +176,142 @@ mod tests {
         }
     }
 
+    mod genes {
+        use super::*;
+
+        #[test]
+        fn test() {
+            assert_eq!(chromosome().genes(), &[3.0, 1.0, 2.0]);
+        }
+    }
+
+    mod distance {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let other = vec![3.0, 5.0, 5.0].into_iter().collect();
+            approx::assert_relative_eq!(chromosome().distance(&other), 5.0);
+            approx::assert_relative_eq!(chromosome().distance(&chromosome()), 0.0);
+        }
+
+        #[test]
+        fn bits() {
+            let a: Chromosome<bool> = vec![true, false, true].into_iter().collect();
+            let b: Chromosome<bool> = vec![false, false, false].into_iter().collect();
+            approx::assert_relative_eq!(a.distance(&b), 2.0f32.sqrt());
+        }
+    }
+
+    mod genes_mut {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut chromosome = chromosome();
+            chromosome.genes_mut().swap(0, 2);
+            assert_eq!(chromosome.genes(), &[2.0, 1.0, 3.0]);
+        }
+    }
+
+    mod eq {
+        use super::*;
+
+        #[test]
+        fn real() {
+            assert_eq!(chromosome(), chromosome());
+            assert_ne!(chromosome(), Chromosome::from_iter(vec![3.0, 1.0]));
+            assert_ne!(chromosome(), Chromosome::from_iter(vec![3.0, 1.0, 2.1]));
+        }
+
+        #[test]
+        fn bits() {
+            let chromosome: Chromosome<bool> = vec![true, false].into_iter().collect();
+            assert_eq!(chromosome, Chromosome::from_iter(vec![true, false]));
+            assert_ne!(chromosome, Chromosome::from_iter(vec![true, true]));
+        }
+    }
+
+    mod is_empty {
+        use super::*;
+
+        #[test]
+        fn test() {
+            assert!(!chromosome().is_empty());
+            assert!(Chromosome::<f32>::from_iter(Vec::new()).is_empty());
+        }
+    }
+
+    mod strategy {
+        use super::*;
+
+        #[test]
+        fn empty_by_default() {
+            assert!(!chromosome().has_strategy());
+            assert!(chromosome().strategy().is_empty());
+        }
+
+        #[test]
+        fn with_strategy() {
+            let chromosome = chromosome().with_strategy(vec![0.1, 0.2, 0.3]);
+            assert!(chromosome.has_strategy());
+            assert_eq!(chromosome.strategy(), &[0.1, 0.2, 0.3]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn with_strategy_of_wrong_length() {
+            chromosome().with_strategy(vec![0.1]);
+        }
+
+        #[test]
+        fn iter_mut_with_strategy() {
+            let mut chromosome = chromosome().with_strategy(vec![0.1, 0.2, 0.3]);
+            chromosome
+                .iter_mut_with_strategy()
+                .for_each(|(gene, sigma)| {
+                    *gene += 1.0;
+                    *sigma *= 2.0;
+                });
+
+            let expected = Chromosome {
+                genes: vec![4.0, 2.0, 3.0],
+                strategy: vec![0.2, 0.4, 0.6],
+            };
+            assert_eq!(chromosome, expected);
+        }
+
+        #[test]
+        fn is_compared() {
+            assert_ne!(
+                chromosome(),
+                chromosome().with_strategy(vec![0.1, 0.2, 0.3])
+            );
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn deserialize() {
+            let chromosome: Chromosome =
+                serde_json::from_str(r#"{"genes":[3.0,1.0,2.0],"strategy":[0.1,0.2,0.3]}"#)
+                    .unwrap();
+            assert_eq!(chromosome.strategy(), &[0.1, 0.2, 0.3]);
+
+            let chromosome: Chromosome =
+                serde_json::from_str(r#"{"genes":[3.0,1.0,2.0]}"#).unwrap();
+            assert!(!chromosome.has_strategy());
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn deserialize_wrong_length() {
+            let error =
+                serde_json::from_str::<Chromosome>(r#"{"genes":[3.0,1.0,2.0],"strategy":[0.1]}"#)
+                    .unwrap_err();
+            assert!(error.to_string().contains("for each of the 3 genes, got 1"));
+        }
+    }
+
     mod iter {
         use super::*;
 
@@ -134,6 +376,7 @@ mod tests {
         fn test() {
             let chromosome = Chromosome {
                 genes: vec![3.0, 1.0, 2.0],
+                strategy: Vec::new(),
             };
 
             let genes: Vec<_> = chromosome.into_iter().collect();
@@ -144,4 +387,21 @@ mod tests {
             assert_eq!(genes[2], 2.0);
         }
     }
+
+    mod segment {
+        use super::*;
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        #[test]
+        fn is_within_bounds() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            for len in 2..20 {
+                for _ in 0..100 {
+                    let (start, end) = segment(&mut rng, len);
+                    assert!(start + 2 <= end && end <= len);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,375 @@
+use crate::*;
+use nalgebra::{DMatrix, DVector, SymmetricEigen};
+use rand_distr::{Distribution, StandardNormal};
+
+// Covariance Matrix Adaptation Evolution Strategy (Hansen, "The CMA Evolution
+// Strategy: A Tutorial", 2016). Instead of breeding individuals with each
+// other, samples every generation from a multivariate normal distribution
+// and moves that distribution towards the fittest samples, learning along
+// the way how the genes depend on each other. Often converges much faster
+// than a genetic algorithm on real-valued problems like neural weights.
+//
+// `ask` samples chromosomes to evaluate, `tell` updates the distribution
+// from the evaluated individuals; `evolve` does both, so it can stand in
+// for `GeneticAlgorithm::evolve`. Fitness is maximized.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CmaEs {
+    // Number of samples per generation (lambda) and how many of the fittest
+    // ones the distribution moves towards (mu), along with their weights
+    population_size: usize,
+    weights: DVector<f64>,
+    mu_eff: f64,
+    // Learning rates of the evolution paths, the covariance matrix and the
+    // step size
+    cc: f64,
+    cs: f64,
+    c1: f64,
+    cmu: f64,
+    damps: f64,
+    // Expected length of a standard normally distributed vector
+    chi_n: f64,
+    mean: DVector<f64>,
+    sigma: f64,
+    pc: DVector<f64>,
+    ps: DVector<f64>,
+    covariance: DMatrix<f64>,
+    // Eigendecomposition of `covariance`: the columns of `b` are its
+    // eigenvectors, `d` the square roots of its eigenvalues
+    b: DMatrix<f64>,
+    d: DVector<f64>,
+    // Generation `b` and `d` were last brought up to date in; decomposing
+    // is O(n^3), so it's done only every few generations
+    eigen_generation: usize,
+    generation: usize,
+}
+
+impl CmaEs {
+    // Starts out with a spherical distribution around `mean`
+    pub fn new(mean: &Chromosome, sigma: f32) -> Self {
+        let n = mean.len();
+        assert!(n > 0);
+        Self::with_population_size(mean, sigma, 4 + (3.0 * (n as f64).ln()) as usize)
+    }
+
+    pub fn with_population_size(mean: &Chromosome, sigma: f32, population_size: usize) -> Self {
+        assert!(!mean.is_empty());
+        assert!(sigma > 0.0);
+        assert!(population_size >= 2);
+
+        let n = mean.len() as f64;
+        let mu = population_size / 2;
+        let weights = DVector::from_iterator(
+            mu,
+            (1..=mu).map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln()),
+        );
+        let weights = &weights / weights.sum();
+        let mu_eff = 1.0 / weights.norm_squared();
+
+        let cc = (4.0 + mu_eff / n) / (n + 4.0 + 2.0 * mu_eff / n);
+        let cs = (mu_eff + 2.0) / (n + mu_eff + 5.0);
+        let c1 = 2.0 / ((n + 1.3).powi(2) + mu_eff);
+        let cmu =
+            (1.0 - c1).min(2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / ((n + 2.0).powi(2) + mu_eff));
+        let damps = 1.0 + 2.0 * (((mu_eff - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        let dimension = mean.len();
+        Self {
+            population_size,
+            weights,
+            mu_eff,
+            cc,
+            cs,
+            c1,
+            cmu,
+            damps,
+            chi_n,
+            mean: DVector::from_iterator(dimension, mean.iter().map(|&gene| gene as f64)),
+            sigma: sigma as f64,
+            pc: DVector::zeros(dimension),
+            ps: DVector::zeros(dimension),
+            covariance: DMatrix::identity(dimension, dimension),
+            b: DMatrix::identity(dimension, dimension),
+            d: DVector::from_element(dimension, 1.0),
+            eigen_generation: 0,
+            generation: 0,
+        }
+    }
+
+    pub fn population_size(&self) -> usize {
+        self.population_size
+    }
+
+    pub fn mean(&self) -> Chromosome {
+        self.mean.iter().map(|&gene| gene as f32).collect()
+    }
+
+    pub fn sigma(&self) -> f32 {
+        self.sigma as f32
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // Samples a generation's worth of chromosomes to be evaluated
+    pub fn ask(&self, rng: &mut dyn RngCore) -> Vec<Chromosome> {
+        let dimension = self.mean.len();
+
+        (0..self.population_size)
+            .map(|_| {
+                let z = DVector::from_fn(dimension, |_, _| StandardNormal.sample(rng));
+                let x = &self.mean + self.sigma * (&self.b * self.d.component_mul(&z));
+                x.iter().map(|&gene| gene as f32).collect()
+            })
+            .collect()
+    }
+
+    // Moves the distribution towards the fittest of the evaluated
+    // individuals, which don't strictly have to come from `ask`
+    pub fn tell<I>(&mut self, population: &[I]) -> Statistics
+    where
+        I: Individual<Gene = f32>,
+    {
+        assert_eq!(
+            population.len(),
+            self.population_size,
+            "got a population of the wrong size"
+        );
+
+        let stats = Statistics::new(population);
+        let n = self.mean.len() as f64;
+
+        // Fittest first; NaN (e.g. a failed evaluation) ranks last
+        let fitness = |individual: &&I| {
+            let fitness = individual.fitness();
+            if fitness.is_nan() {
+                f32::NEG_INFINITY
+            } else {
+                fitness
+            }
+        };
+        let mut ranked: Vec<_> = population.iter().collect();
+        ranked.sort_by(|a, b| fitness(b).total_cmp(&fitness(a)));
+        // Steps from the old mean, in units of `sigma`, of the fittest ones
+        let steps: Vec<_> = ranked[..self.weights.len()]
+            .iter()
+            .map(|individual| {
+                let chromosome = individual.chromosome();
+                assert_eq!(chromosome.len(), self.mean.len());
+                let x = DVector::from_iterator(
+                    chromosome.len(),
+                    chromosome.iter().map(|&gene| gene as f64),
+                );
+                (x - &self.mean) / self.sigma
+            })
+            .collect();
+        let step = steps
+            .iter()
+            .zip(self.weights.iter())
+            .fold(DVector::zeros(self.mean.len()), |sum, (step, &weight)| {
+                sum + weight * step
+            });
+
+        self.mean += self.sigma * &step;
+        self.generation += 1;
+
+        // Evolution paths: where the mean has been heading lately, with and
+        // without the covariance's shape taken out
+        let inv_sqrt_covariance =
+            &self.b * DMatrix::from_diagonal(&self.d.map(|d| 1.0 / d)) * self.b.transpose();
+        self.ps = (1.0 - self.cs) * &self.ps
+            + (self.cs * (2.0 - self.cs) * self.mu_eff).sqrt() * (inv_sqrt_covariance * &step);
+        let ps_norm = self.ps.norm();
+        // Stall the update of `pc` while the step size is growing quickly
+        let hsig =
+            ps_norm / (1.0 - (1.0 - self.cs).powi(2 * self.generation as i32)).sqrt() / self.chi_n
+                < 1.4 + 2.0 / (n + 1.0);
+        let hsig = if hsig { 1.0 } else { 0.0 };
+        self.pc = (1.0 - self.cc) * &self.pc
+            + hsig * (self.cc * (2.0 - self.cc) * self.mu_eff).sqrt() * &step;
+
+        // Rank-one update from the evolution path, rank-mu update from this
+        // generation's steps
+        let rank_one = &self.pc * self.pc.transpose();
+        let rank_mu = steps.iter().zip(self.weights.iter()).fold(
+            DMatrix::zeros(step.len(), step.len()),
+            |sum, (step, &weight)| sum + weight * step * step.transpose(),
+        );
+        self.covariance = (1.0 - self.c1 - self.cmu) * &self.covariance
+            + self.c1 * (rank_one + (1.0 - hsig) * self.cc * (2.0 - self.cc) * &self.covariance)
+            + self.cmu * rank_mu;
+
+        self.sigma *= ((self.cs / self.damps) * (ps_norm / self.chi_n - 1.0)).exp();
+
+        let eigen_interval = self.population_size as f64 / (self.c1 + self.cmu) / n / 10.0;
+        if (self.generation - self.eigen_generation) as f64 > eigen_interval {
+            self.decompose();
+        }
+
+        stats
+    }
+
+    // Counterpart of `GeneticAlgorithm::evolve`: learns from the evaluated
+    // population and samples the next one
+    pub fn evolve<I>(&mut self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics)
+    where
+        I: Individual<Gene = f32>,
+    {
+        let stats = self.tell(population);
+        let population = self.ask(rng).into_iter().map(I::create).collect();
+        (population, stats)
+    }
+
+    fn decompose(&mut self) {
+        self.eigen_generation = self.generation;
+        // Rounding errors would slowly make it asymmetric
+        self.covariance = (&self.covariance + self.covariance.transpose()) / 2.0;
+
+        let eigen = SymmetricEigen::new(self.covariance.clone());
+        self.b = eigen.eigenvectors;
+        self.d = eigen
+            .eigenvalues
+            .map(|value| value.max(f64::EPSILON).sqrt());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    // The closer to (1, 2, 3, 4, 5) the better
+    #[derive(Clone, Debug)]
+    struct Sphere(Chromosome, f32);
+
+    impl Individual for Sphere {
+        type Gene = f32;
+
+        fn fitness(&self) -> f32 {
+            self.1
+        }
+        fn chromosome(&self) -> &Chromosome {
+            &self.0
+        }
+        fn create(chromosome: Chromosome) -> Self {
+            let fitness = -chromosome
+                .iter()
+                .enumerate()
+                .map(|(i, gene)| (gene - (i + 1) as f32).powi(2))
+                .sum::<f32>();
+            Self(chromosome, fitness)
+        }
+    }
+
+    fn start() -> Chromosome {
+        vec![0.0; 5].into_iter().collect()
+    }
+
+    #[test]
+    fn ask() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let cma_es = CmaEs::new(&start(), 0.5);
+
+        let chromosomes = cma_es.ask(&mut rng);
+
+        // 4 + 3 ln(5)
+        assert_eq!(chromosomes.len(), 8);
+        assert!(chromosomes.iter().all(|chromosome| chromosome.len() == 5));
+        assert_ne!(chromosomes[0], chromosomes[1]);
+    }
+
+    #[test]
+    fn converges() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut cma_es = CmaEs::new(&start(), 0.5);
+        let mut population: Vec<_> = cma_es
+            .ask(&mut rng)
+            .into_iter()
+            .map(Sphere::create)
+            .collect();
+
+        for _ in 0..150 {
+            (population, _) = cma_es.evolve(&mut rng, &population);
+        }
+
+        assert_eq!(cma_es.generation(), 150);
+        for (gene, expected) in cma_es.mean().iter().zip([1.0, 2.0, 3.0, 4.0, 5.0]) {
+            approx::assert_relative_eq!(*gene, expected, epsilon = 1e-3);
+        }
+        assert!(cma_es.sigma() < 0.01);
+    }
+
+    // Genes on scales a hundredfold apart, which takes learning the covariance
+    // rather than stepping the same amount in every direction
+    #[test]
+    fn adapts_to_ill_conditioning() {
+        #[derive(Clone, Debug)]
+        struct Ellipsoid(Chromosome, f32);
+
+        impl Individual for Ellipsoid {
+            type Gene = f32;
+
+            fn fitness(&self) -> f32 {
+                self.1
+            }
+            fn chromosome(&self) -> &Chromosome {
+                &self.0
+            }
+            fn create(chromosome: Chromosome) -> Self {
+                let fitness = -chromosome
+                    .iter()
+                    .enumerate()
+                    .map(|(i, gene)| 100f32.powi(i as i32) * gene * gene)
+                    .sum::<f32>();
+                Self(chromosome, fitness)
+            }
+        }
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let start: Chromosome = vec![1.0; 3].into_iter().collect();
+        let mut cma_es = CmaEs::with_population_size(&start, 0.5, 10);
+        let initial = Ellipsoid::create(start).fitness();
+        let mut population: Vec<_> = cma_es
+            .ask(&mut rng)
+            .into_iter()
+            .map(Ellipsoid::create)
+            .collect();
+
+        for _ in 0..200 {
+            (population, _) = cma_es.evolve(&mut rng, &population);
+        }
+
+        let best = Ellipsoid::create(cma_es.mean()).fitness();
+        assert!(best > initial * 1e-6, "got {} from {}", best, initial);
+    }
+
+    #[test]
+    fn nan_ranks_last() {
+        let tell = |fitness: f32| {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut cma_es = CmaEs::new(&start(), 0.5);
+            let mut population: Vec<_> = cma_es
+                .ask(&mut rng)
+                .into_iter()
+                .map(Sphere::create)
+                .collect();
+            population[0].1 = fitness;
+            cma_es.tell(&population);
+            cma_es.mean()
+        };
+
+        assert_eq!(tell(f32::NAN), tell(f32::NEG_INFINITY));
+        assert_ne!(tell(f32::NAN), tell(f32::INFINITY));
+        assert!(tell(f32::NAN).iter().all(|gene| gene.is_finite()));
+    }
+
+    #[test]
+    #[should_panic(expected = "got a population of the wrong size")]
+    fn tell_with_wrong_population_size() {
+        let mut cma_es = CmaEs::new(&start(), 0.5);
+        cma_es.tell(&[Sphere::create(start())]);
+    }
+}
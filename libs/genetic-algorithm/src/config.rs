@@ -0,0 +1,726 @@
+use crate::*;
+use std::fmt;
+
+// Operators and their parameters picked by name, so that different setups
+// can be tried out without recompiling. With the `serde` feature it can be
+// loaded from TOML or JSON, e.g.:
+//
+//   elitism = { count = 2 }
+//
+//   [selection]
+//   name = "tournament"
+//   size = 3
+//
+//   [crossover]
+//   name = "blend"
+//   alpha = 0.5
+//
+//   [mutation]
+//   name = "gaussian"
+//   chance = 0.01
+//   coeff = 0.3
+//
+//   [[fitness_transforms]]
+//   name = "sharing"
+//   radius = 1.0
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneticAlgorithmConfig {
+    pub selection: SelectionConfig,
+    pub crossover: CrossoverConfig,
+    pub mutation: MutationConfig,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub elitism: Elitism,
+    // Applied in the order they're listed
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fitness_transforms: Vec<TransformConfig>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hall_of_fame: Option<HallOfFameConfig>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub history: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "name", rename_all = "snake_case")
+)]
+pub enum SelectionConfig {
+    RouletteWheel,
+    Tournament {
+        size: usize,
+        // Defaults to 1.0, i.e. the fittest contestant always wins
+        probability: Option<f32>,
+    },
+    LinearRank {
+        pressure: f32,
+    },
+    ExponentialRank {
+        base: f32,
+    },
+    StochasticUniversal,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "name", rename_all = "snake_case")
+)]
+pub enum CrossoverConfig {
+    Uniform,
+    SinglePoint,
+    KPoint { points: usize },
+    Arithmetic { weight: f32 },
+    Blend { alpha: f32 },
+    SimulatedBinary { eta: f32 },
+    SelfAdaptive,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "name", rename_all = "snake_case")
+)]
+pub enum MutationConfig {
+    Gaussian {
+        chance: f32,
+        coeff: f32,
+    },
+    Normal {
+        chance: f32,
+        sigma: f32,
+    },
+    Cauchy {
+        chance: f32,
+        scale: f32,
+    },
+    Polynomial {
+        chance: f32,
+        eta: f32,
+        min: f32,
+        max: f32,
+    },
+    UniformReset {
+        chance: f32,
+        min: f32,
+        max: f32,
+    },
+    Adaptive {
+        chance: f32,
+        coeff: f32,
+        window: Option<usize>,
+        patience: Option<usize>,
+    },
+    SelfAdaptive {
+        initial_sigma: f32,
+        min_sigma: Option<f32>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "name", rename_all = "snake_case")
+)]
+pub enum TransformConfig {
+    Sharing {
+        radius: f32,
+        alpha: Option<f32>,
+    },
+    Clearing {
+        radius: f32,
+        capacity: usize,
+    },
+    Linear {
+        multiple: f32,
+    },
+    SigmaTruncation {
+        c: f32,
+    },
+    Power {
+        exponent: f32,
+    },
+    Boltzmann {
+        temperature: f32,
+        // Both default to keeping the temperature constant
+        cooling: Option<f32>,
+        min_temperature: Option<f32>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HallOfFameConfig {
+    pub capacity: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reinjection: usize,
+}
+
+// A configured value the operator it's meant for doesn't accept, e.g. a
+// mutation chance of 1.5
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigError {
+    operator: &'static str,
+    parameter: &'static str,
+    value: String,
+    expected: &'static str,
+}
+
+impl ConfigError {
+    pub fn operator(&self) -> &str {
+        self.operator
+    }
+
+    pub fn parameter(&self) -> &str {
+        self.parameter
+    }
+
+    fn check(
+        valid: bool,
+        operator: &'static str,
+        parameter: &'static str,
+        value: impl fmt::Display,
+        expected: &'static str,
+    ) -> Result<(), Self> {
+        if valid {
+            Ok(())
+        } else {
+            Err(Self {
+                operator,
+                parameter,
+                value: value.to_string(),
+                expected,
+            })
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} must be {}, got {}",
+            self.operator, self.parameter, self.expected, self.value
+        )
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+const PROBABILITY: &str = "within [0.0, 1.0]";
+
+fn check_chance(operator: &'static str, chance: f32) -> Result<(), ConfigError> {
+    ConfigError::check(
+        (0.0..=1.0).contains(&chance),
+        operator,
+        "chance",
+        chance,
+        PROBABILITY,
+    )
+}
+
+impl GeneticAlgorithmConfig {
+    pub fn new(
+        selection: SelectionConfig,
+        crossover: CrossoverConfig,
+        mutation: MutationConfig,
+    ) -> Self {
+        Self {
+            selection,
+            crossover,
+            mutation,
+            elitism: Elitism::default(),
+            fitness_transforms: Vec::new(),
+            hall_of_fame: None,
+            history: false,
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn from_toml(config: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(config)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn from_json(config: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(config)
+    }
+
+    pub fn build(&self) -> Result<GeneticAlgorithm<AnySelection>, ConfigError> {
+        let mut ga = GeneticAlgorithmBuilder::from_config(self)?.build();
+        ga.config = Some(self.clone());
+        Ok(ga)
+    }
+}
+
+impl SelectionConfig {
+    pub fn build(&self) -> Result<AnySelection, ConfigError> {
+        Ok(match *self {
+            Self::RouletteWheel => RouletteWheelSelection::new().into(),
+            Self::Tournament { size, probability } => {
+                let probability = probability.unwrap_or(1.0);
+                ConfigError::check(size > 0, "tournament", "size", size, "at least 1")?;
+                ConfigError::check(
+                    (0.0..=1.0).contains(&probability),
+                    "tournament",
+                    "probability",
+                    probability,
+                    PROBABILITY,
+                )?;
+                TournamentSelection::with_probability(size, probability).into()
+            }
+            Self::LinearRank { pressure } => {
+                ConfigError::check(
+                    (1.0..=2.0).contains(&pressure),
+                    "linear_rank",
+                    "pressure",
+                    pressure,
+                    "within [1.0, 2.0]",
+                )?;
+                RankSelection::linear(pressure).into()
+            }
+            Self::ExponentialRank { base } => {
+                ConfigError::check(
+                    base > 0.0 && base <= 1.0,
+                    "exponential_rank",
+                    "base",
+                    base,
+                    "within (0.0, 1.0]",
+                )?;
+                RankSelection::exponential(base).into()
+            }
+            Self::StochasticUniversal => StochasticUniversalSampling::new().into(),
+        })
+    }
+}
+
+impl CrossoverConfig {
+    pub fn build(&self) -> Result<Box<dyn CrossoverMethod>, ConfigError> {
+        Ok(match *self {
+            Self::Uniform => Box::new(UniformCrossover::new()),
+            Self::SinglePoint => Box::new(SinglePointCrossover::new()),
+            Self::KPoint { points } => {
+                ConfigError::check(points > 0, "k_point", "points", points, "at least 1")?;
+                Box::new(KPointCrossover::new(points))
+            }
+            Self::Arithmetic { weight } => {
+                ConfigError::check(
+                    (0.0..=1.0).contains(&weight),
+                    "arithmetic",
+                    "weight",
+                    weight,
+                    PROBABILITY,
+                )?;
+                Box::new(ArithmeticCrossover::new(weight))
+            }
+            Self::Blend { alpha } => {
+                ConfigError::check(alpha >= 0.0, "blend", "alpha", alpha, "at least 0.0")?;
+                Box::new(BlendCrossover::new(alpha))
+            }
+            Self::SimulatedBinary { eta } => {
+                ConfigError::check(eta >= 0.0, "simulated_binary", "eta", eta, "at least 0.0")?;
+                Box::new(SimulatedBinaryCrossover::new(eta))
+            }
+            Self::SelfAdaptive => Box::new(SelfAdaptiveCrossover::new()),
+        })
+    }
+}
+
+impl MutationConfig {
+    pub fn build(&self) -> Result<Box<dyn MutationMethod>, ConfigError> {
+        Ok(match *self {
+            Self::Gaussian { chance, coeff } => {
+                check_chance("gaussian", chance)?;
+                ConfigError::check(coeff >= 0.0, "gaussian", "coeff", coeff, "at least 0.0")?;
+                Box::new(GaussianMethod::new(chance, coeff))
+            }
+            Self::Normal { chance, sigma } => {
+                check_chance("normal", chance)?;
+                ConfigError::check(
+                    sigma >= 0.0 && sigma.is_finite(),
+                    "normal",
+                    "sigma",
+                    sigma,
+                    "finite and at least 0.0",
+                )?;
+                Box::new(NormalMutation::new(chance, sigma))
+            }
+            Self::Cauchy { chance, scale } => {
+                check_chance("cauchy", chance)?;
+                ConfigError::check(
+                    scale > 0.0 && scale.is_finite(),
+                    "cauchy",
+                    "scale",
+                    scale,
+                    "finite and above 0.0",
+                )?;
+                Box::new(CauchyMutation::new(chance, scale))
+            }
+            Self::Polynomial {
+                chance,
+                eta,
+                min,
+                max,
+            } => {
+                check_chance("polynomial", chance)?;
+                ConfigError::check(eta >= 0.0, "polynomial", "eta", eta, "at least 0.0")?;
+                ConfigError::check(min < max, "polynomial", "max", max, "above min")?;
+                Box::new(PolynomialMutation::new(chance, eta, min, max))
+            }
+            Self::UniformReset { chance, min, max } => {
+                check_chance("uniform_reset", chance)?;
+                ConfigError::check(min < max, "uniform_reset", "max", max, "above min")?;
+                Box::new(UniformResetMutation::new(chance, min, max))
+            }
+            Self::Adaptive {
+                chance,
+                coeff,
+                window,
+                patience,
+            } => {
+                check_chance("adaptive", chance)?;
+                ConfigError::check(coeff > 0.0, "adaptive", "coeff", coeff, "above 0.0")?;
+                let mut mutation = AdaptiveMutation::new(chance, coeff);
+                if let Some(window) = window {
+                    ConfigError::check(window > 0, "adaptive", "window", window, "at least 1")?;
+                    mutation = mutation.with_window(window);
+                }
+                if let Some(patience) = patience {
+                    ConfigError::check(
+                        patience > 0,
+                        "adaptive",
+                        "patience",
+                        patience,
+                        "at least 1",
+                    )?;
+                    mutation = mutation.with_patience(patience);
+                }
+                Box::new(mutation)
+            }
+            Self::SelfAdaptive {
+                initial_sigma,
+                min_sigma,
+            } => {
+                ConfigError::check(
+                    initial_sigma > 0.0,
+                    "self_adaptive",
+                    "initial_sigma",
+                    initial_sigma,
+                    "above 0.0",
+                )?;
+                let mut mutation = SelfAdaptiveMutation::new(initial_sigma);
+                if let Some(min_sigma) = min_sigma {
+                    ConfigError::check(
+                        min_sigma > 0.0,
+                        "self_adaptive",
+                        "min_sigma",
+                        min_sigma,
+                        "above 0.0",
+                    )?;
+                    mutation = mutation.with_min_sigma(min_sigma);
+                }
+                Box::new(mutation)
+            }
+        })
+    }
+}
+
+impl TransformConfig {
+    pub fn build(&self) -> Result<Box<dyn FitnessTransform>, ConfigError> {
+        Ok(match *self {
+            Self::Sharing { radius, alpha } => {
+                ConfigError::check(radius > 0.0, "sharing", "radius", radius, "above 0.0")?;
+                match alpha {
+                    Some(alpha) => {
+                        ConfigError::check(alpha > 0.0, "sharing", "alpha", alpha, "above 0.0")?;
+                        Box::new(FitnessSharing::with_alpha(radius, alpha))
+                    }
+                    None => Box::new(FitnessSharing::new(radius)),
+                }
+            }
+            Self::Clearing { radius, capacity } => {
+                ConfigError::check(radius > 0.0, "clearing", "radius", radius, "above 0.0")?;
+                ConfigError::check(capacity > 0, "clearing", "capacity", capacity, "at least 1")?;
+                Box::new(Clearing::new(radius, capacity))
+            }
+            Self::Linear { multiple } => {
+                ConfigError::check(
+                    multiple >= 1.0,
+                    "linear",
+                    "multiple",
+                    multiple,
+                    "at least 1.0",
+                )?;
+                Box::new(LinearScaling::new(multiple))
+            }
+            Self::SigmaTruncation { c } => {
+                ConfigError::check(c >= 0.0, "sigma_truncation", "c", c, "at least 0.0")?;
+                Box::new(SigmaTruncation::new(c))
+            }
+            Self::Power { exponent } => {
+                ConfigError::check(exponent > 0.0, "power", "exponent", exponent, "above 0.0")?;
+                Box::new(PowerScaling::new(exponent))
+            }
+            Self::Boltzmann {
+                temperature,
+                cooling,
+                min_temperature,
+            } => {
+                let cooling = cooling.unwrap_or(1.0);
+                let min_temperature = min_temperature.unwrap_or(temperature);
+                ConfigError::check(
+                    min_temperature > 0.0,
+                    "boltzmann",
+                    "min_temperature",
+                    min_temperature,
+                    "above 0.0",
+                )?;
+                ConfigError::check(
+                    temperature >= min_temperature,
+                    "boltzmann",
+                    "temperature",
+                    temperature,
+                    "at least min_temperature",
+                )?;
+                ConfigError::check(
+                    cooling > 0.0 && cooling <= 1.0,
+                    "boltzmann",
+                    "cooling",
+                    cooling,
+                    "within (0.0, 1.0]",
+                )?;
+                Box::new(BoltzmannScaling::with_cooling(
+                    temperature,
+                    cooling,
+                    min_temperature,
+                ))
+            }
+        })
+    }
+}
+
+impl HallOfFameConfig {
+    pub fn build(&self) -> Result<HallOfFame, ConfigError> {
+        ConfigError::check(
+            self.capacity > 0,
+            "hall_of_fame",
+            "capacity",
+            self.capacity,
+            "at least 1",
+        )?;
+        ConfigError::check(
+            self.reinjection <= self.capacity,
+            "hall_of_fame",
+            "reinjection",
+            self.reinjection,
+            "at most capacity",
+        )?;
+        Ok(HallOfFame::new(self.capacity).with_reinjection(self.reinjection))
+    }
+}
+
+// A bad fraction would only panic once there's a population to count elites
+// in
+pub(crate) fn check_elitism(elitism: Elitism) -> Result<(), ConfigError> {
+    match elitism {
+        Elitism::Count(_) => Ok(()),
+        Elitism::Fraction(fraction) => ConfigError::check(
+            (0.0..=1.0).contains(&fraction),
+            "elitism",
+            "fraction",
+            fraction,
+            PROBABILITY,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn config() -> GeneticAlgorithmConfig {
+        GeneticAlgorithmConfig {
+            elitism: Elitism::Count(2),
+            fitness_transforms: vec![TransformConfig::Sharing {
+                radius: 1.0,
+                alpha: None,
+            }],
+            ..GeneticAlgorithmConfig::new(
+                SelectionConfig::Tournament {
+                    size: 3,
+                    probability: None,
+                },
+                CrossoverConfig::Blend { alpha: 0.5 },
+                MutationConfig::Gaussian {
+                    chance: 0.01,
+                    coeff: 0.3,
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn builds_the_configured_operators() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population: Vec<_> = (0..10)
+            .map(|n| TestIndividual::create(vec![n as f32, 1.0].into_iter().collect()))
+            .collect();
+
+        let (expected, _) = GeneticAlgorithm::new(
+            TournamentSelection::new(3),
+            BlendCrossover::new(0.5),
+            GaussianMethod::new(0.01, 0.3),
+        )
+        .with_elitism(Elitism::Count(2))
+        .with_fitness_transform(FitnessSharing::new(1.0))
+        .evolve(&mut rng.clone(), &population);
+        let (actual, _) = config().build().unwrap().evolve(&mut rng, &population);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_toml() {
+        let config = GeneticAlgorithmConfig::from_toml(
+            r#"
+                elitism = { count = 2 }
+
+                [selection]
+                name = "tournament"
+                size = 3
+
+                [crossover]
+                name = "blend"
+                alpha = 0.5
+
+                [mutation]
+                name = "gaussian"
+                chance = 0.01
+                coeff = 0.3
+
+                [[fitness_transforms]]
+                name = "sharing"
+                radius = 1.0
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config, self::config());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_json() {
+        let config = GeneticAlgorithmConfig::from_json(
+            r#"{
+                "selection": { "name": "roulette_wheel" },
+                "crossover": { "name": "k_point", "points": 2 },
+                "mutation": { "name": "adaptive", "chance": 0.1, "coeff": 0.5, "window": 5 },
+                "hall_of_fame": { "capacity": 3 },
+                "history": true
+            }"#,
+        )
+        .unwrap();
+
+        let expected = GeneticAlgorithmConfig {
+            hall_of_fame: Some(HallOfFameConfig {
+                capacity: 3,
+                reinjection: 0,
+            }),
+            history: true,
+            ..GeneticAlgorithmConfig::new(
+                SelectionConfig::RouletteWheel,
+                CrossoverConfig::KPoint { points: 2 },
+                MutationConfig::Adaptive {
+                    chance: 0.1,
+                    coeff: 0.5,
+                    window: Some(5),
+                    patience: None,
+                },
+            )
+        };
+        assert_eq!(config, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn unknown_operator() {
+        let config = GeneticAlgorithmConfig::from_json(
+            r#"{
+                "selection": { "name": "lottery" },
+                "crossover": { "name": "uniform" },
+                "mutation": { "name": "gaussian", "chance": 0.1, "coeff": 0.5 }
+            }"#,
+        );
+
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn out_of_range() {
+        let config = GeneticAlgorithmConfig {
+            mutation: MutationConfig::Gaussian {
+                chance: 1.5,
+                coeff: 0.3,
+            },
+            ..config()
+        };
+
+        let error = config.build().err().unwrap();
+        assert_eq!(error.operator(), "gaussian");
+        assert_eq!(error.parameter(), "chance");
+        assert_eq!(
+            error.to_string(),
+            "gaussian chance must be within [0.0, 1.0], got 1.5"
+        );
+
+        let config = GeneticAlgorithmConfig {
+            hall_of_fame: Some(HallOfFameConfig {
+                capacity: 2,
+                reinjection: 3,
+            }),
+            ..self::config()
+        };
+        assert_eq!(config.build().err().unwrap().parameter(), "reinjection");
+
+        let config = GeneticAlgorithmConfig {
+            selection: SelectionConfig::Tournament {
+                size: 0,
+                probability: None,
+            },
+            ..self::config()
+        };
+        assert_eq!(config.build().err().unwrap().parameter(), "size");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn out_of_range_from_toml() {
+        let config = GeneticAlgorithmConfig::from_toml(
+            r#"
+                elitism = { fraction = 2.0 }
+
+                [selection]
+                name = "roulette_wheel"
+
+                [crossover]
+                name = "uniform"
+
+                [mutation]
+                name = "gaussian"
+                chance = 0.1
+                coeff = 0.5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.build().err().unwrap().operator(), "elitism");
+    }
+}
@@ -1,14 +1,25 @@
-pub use self::uniform::*;
+pub use self::{
+    arithmetic::*, blend::*, k_point::*, order::*, partially_mapped::*, self_adaptive::*,
+    simulated_binary::*, single_point::*, uniform::*,
+};
 
 use crate::*;
 
+mod arithmetic;
+mod blend;
+mod k_point;
+mod order;
+mod partially_mapped;
+mod self_adaptive;
+mod simulated_binary;
+mod single_point;
 mod uniform;
 
-pub trait CrossoverMethod {
+pub trait CrossoverMethod<G = f32>: Send + Sync {
     fn crossover(
         &self,
         rng: &mut dyn RngCore,
-        parent_a: &Chromosome,
-        parent_b: &Chromosome,
-    ) -> Chromosome;
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G>;
 }
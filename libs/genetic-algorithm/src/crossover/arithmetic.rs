@@ -0,0 +1,75 @@
+use crate::*;
+
+// Child genes are the weighted average `weight * a + (1 - weight) * b` of the
+// parents' genes
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArithmeticCrossover {
+    weight: f32,
+}
+
+impl ArithmeticCrossover {
+    pub fn new(weight: f32) -> Self {
+        assert!((0.0..=1.0).contains(&weight));
+        Self { weight }
+    }
+}
+
+impl Default for ArithmeticCrossover {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl CrossoverMethod for ArithmeticCrossover {
+    fn crossover(
+        &self,
+        _rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| self.weight * a + (1.0 - self.weight) * b)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(weight: f32) -> Vec<f32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0, 3.0, 4.0].into_iter().collect();
+        let parent_b: Chromosome = vec![-1.0, 0.0, 5.0, 4.0].into_iter().collect();
+
+        ArithmeticCrossover::new(weight)
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn average() {
+        let expected = vec![0.0, 1.0, 4.0, 4.0];
+        approx::assert_relative_eq!(actual(0.5).as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn weighted_average() {
+        let expected = vec![0.5, 1.5, 3.5, 4.0];
+        approx::assert_relative_eq!(actual(0.75).as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn full_weight_copies_parent() {
+        let expected = vec![1.0, 2.0, 3.0, 4.0];
+        approx::assert_relative_eq!(actual(1.0).as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::*;
+use rand::Rng;
+
+// BLX-α: each child gene is drawn uniformly from the interval spanned by the
+// parents' genes, extended on both sides by `alpha` times its width
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlendCrossover {
+    alpha: f32,
+}
+
+impl BlendCrossover {
+    pub fn new(alpha: f32) -> Self {
+        assert!(alpha >= 0.0);
+        Self { alpha }
+    }
+}
+
+impl Default for BlendCrossover {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl CrossoverMethod for BlendCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| {
+                let extension = self.alpha * (a - b).abs();
+                let min = a.min(b) - extension;
+                let max = a.max(b) + extension;
+                min + rng.gen::<f32>() * (max - min)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn random_chromosome(rng: &mut dyn RngCore) -> Chromosome {
+        (0..20).map(|_| rng.gen_range(-10.0..10.0)).collect()
+    }
+
+    #[test]
+    fn genes_stay_within_extended_interval() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        for alpha in [0.0, 0.25, 0.5, 1.0] {
+            let method = BlendCrossover::new(alpha);
+
+            for _ in 0..500 {
+                let parent_a = random_chromosome(&mut rng);
+                let parent_b = random_chromosome(&mut rng);
+                let child = method.crossover(&mut rng, &parent_a, &parent_b);
+
+                for ((&a, &b), &c) in parent_a.iter().zip(parent_b.iter()).zip(child.iter()) {
+                    let extension = alpha * (a - b).abs();
+                    assert!(c >= a.min(b) - extension - 1e-4);
+                    assert!(c <= a.max(b) + extension + 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn genes_explore_outside_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let method = BlendCrossover::new(0.5);
+        let parent_a: Chromosome = vec![0.0; 1000].into_iter().collect();
+        let parent_b: Chromosome = vec![1.0; 1000].into_iter().collect();
+
+        let child = method.crossover(&mut rng, &parent_a, &parent_b);
+
+        // Half of the sampling interval lies outside of the parents
+        let outside = child.iter().filter(|&&c| !(0.0..=1.0).contains(&c)).count();
+        assert_eq!(outside, 477);
+    }
+
+    #[test]
+    fn identical_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent: Chromosome = vec![1.0, -2.0, 3.0].into_iter().collect();
+
+        let child = BlendCrossover::new(0.5).crossover(&mut rng, &parent, &parent);
+
+        assert_eq!(child, parent);
+    }
+}
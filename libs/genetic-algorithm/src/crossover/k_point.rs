@@ -0,0 +1,128 @@
+use crate::*;
+use rand::seq::index;
+
+// Generalisation of `SinglePointCrossover` which cuts the chromosome in `k`
+// random places and alternates between the parents at every cut
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KPointCrossover {
+    points: usize,
+}
+
+impl KPointCrossover {
+    pub fn new(points: usize) -> Self {
+        assert!(points > 0);
+        Self { points }
+    }
+}
+
+impl<G> CrossoverMethod<G> for KPointCrossover
+where
+    G: Gene,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G> {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        if parent_a.len() < 2 {
+            return parent_a.clone();
+        }
+
+        // Cuts are distinct and strictly inside, so there can be at most
+        // one between every pair of neighbouring genes
+        let points = self.points.min(parent_a.len() - 1);
+        let mut cuts: Vec<_> = index::sample(rng, parent_a.len() - 1, points)
+            .into_iter()
+            .map(|cut| cut + 1)
+            .collect();
+        cuts.sort_unstable();
+
+        let mut cuts = cuts.into_iter().peekable();
+        let mut from_a = true;
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .enumerate()
+            .map(|(i, (a, b))| {
+                if cuts.next_if_eq(&i).is_some() {
+                    from_a = !from_a;
+                }
+                if from_a {
+                    a.clone()
+                } else {
+                    b.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn parents() -> (Chromosome, Chromosome) {
+        let parent_a = (1..=10).map(|n| n as f32).collect();
+        let parent_b = (1..=10).map(|n| -n as f32).collect();
+        (parent_a, parent_b)
+    }
+
+    fn actual(points: usize) -> Chromosome {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        KPointCrossover::new(points).crossover(&mut rng, &parent_a, &parent_b)
+    }
+
+    // Number of places where the child switches from one parent to the other
+    fn switches(child: &Chromosome) -> usize {
+        let genes: Vec<_> = child.iter().collect();
+        genes
+            .windows(2)
+            .filter(|pair| pair[0].signum() != pair[1].signum())
+            .count()
+    }
+
+    #[test]
+    fn two_points() {
+        let child = actual(2);
+
+        let expected: Chromosome = vec![1.0, 2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, 9.0, 10.0]
+            .into_iter()
+            .collect();
+        assert_eq!(child, expected);
+        assert_eq!(switches(&child), 2);
+    }
+
+    #[test]
+    fn three_points() {
+        let child = actual(3);
+
+        let expected: Chromosome = vec![1.0, 2.0, -3.0, 4.0, 5.0, 6.0, 7.0, 8.0, -9.0, -10.0]
+            .into_iter()
+            .collect();
+        assert_eq!(child, expected);
+        assert_eq!(switches(&child), 3);
+    }
+
+    #[test]
+    fn more_points_than_genes() {
+        let child = actual(100);
+
+        let expected: Chromosome = vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0, 9.0, -10.0]
+            .into_iter()
+            .collect();
+        assert_eq!(child, expected);
+    }
+
+    #[test]
+    fn is_deterministic() {
+        assert_eq!(actual(4), actual(4));
+    }
+}
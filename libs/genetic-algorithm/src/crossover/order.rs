@@ -0,0 +1,104 @@
+use crate::*;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+// OX1 for permutations: a random segment is copied from the first parent, the
+// remaining positions are filled with the missing genes in the order they
+// appear in the second parent, starting right after the segment. Preserves
+// the relative order of genes, e.g. the sequence of cities in a route.
+//
+// Both parents must be permutations of the same set of genes.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderCrossover;
+
+impl OrderCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<G> CrossoverMethod<G> for OrderCrossover
+where
+    G: Gene + Eq + Hash,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G> {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let len = parent_a.len();
+        if len < 2 {
+            return parent_a.clone();
+        }
+
+        let (start, end) = segment(rng, len);
+        let taken: HashSet<_> = parent_a.genes()[start..end].iter().collect();
+
+        let mut child: Vec<Option<G>> = vec![None; len];
+        for i in start..end {
+            child[i] = Some(parent_a[i].clone());
+        }
+
+        let mut remaining = (0..len)
+            .map(|i| &parent_b[(end + i) % len])
+            .filter(|gene| !taken.contains(gene));
+        for i in (0..len - (end - start)).map(|i| (end + i) % len) {
+            child[i] = remaining.next().cloned();
+        }
+
+        child
+            .into_iter()
+            .map(|gene| gene.expect("parents aren't permutations of the same genes"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn parents() -> (Chromosome<usize>, Chromosome<usize>) {
+        let parent_a = (0..10).collect();
+        let parent_b = vec![9, 3, 7, 8, 2, 6, 5, 1, 4, 0].into_iter().collect();
+        (parent_a, parent_b)
+    }
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = OrderCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        assert_eq!(child.genes(), &[3, 2, 6, 5, 1, 4, 0, 7, 8, 9]);
+    }
+
+    #[test]
+    fn children_are_permutations() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        for _ in 0..100 {
+            let child = OrderCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+            let mut genes = child.genes().to_vec();
+            genes.sort_unstable();
+            assert_eq!(genes, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn identical_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent, _) = parents();
+
+        let child = OrderCrossover::new().crossover(&mut rng, &parent, &parent);
+
+        assert_eq!(child, parent);
+    }
+}
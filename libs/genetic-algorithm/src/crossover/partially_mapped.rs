@@ -0,0 +1,112 @@
+use crate::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// PMX for permutations: a random segment is copied from the first parent and
+// everything else from the second one, where genes clashing with the segment
+// are resolved through the mapping between both parents' segments. Preserves
+// the absolute position of most genes.
+//
+// Both parents must be permutations of the same set of genes.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartiallyMappedCrossover;
+
+impl PartiallyMappedCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<G> CrossoverMethod<G> for PartiallyMappedCrossover
+where
+    G: Gene + Eq + Hash,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G> {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let len = parent_a.len();
+        if len < 2 {
+            return parent_a.clone();
+        }
+
+        let (start, end) = segment(rng, len);
+        let position_in_b: HashMap<_, _> = parent_b
+            .iter()
+            .enumerate()
+            .map(|(i, gene)| (gene, i))
+            .collect();
+
+        let mut child = parent_b.genes().to_vec();
+        child[start..end].clone_from_slice(&parent_a.genes()[start..end]);
+
+        // Every gene of b's segment which got overwritten has to find a new
+        // place outside of the segment: follow the mapping a[i] -> b's
+        // position of a[i] until it leads out of the segment
+        for i in start..end {
+            let gene = &parent_b[i];
+            if parent_a.genes()[start..end].contains(gene) {
+                continue;
+            }
+
+            let mut position = i;
+            while (start..end).contains(&position) {
+                position = position_in_b[&parent_a[position]];
+            }
+            child[position] = gene.clone();
+        }
+
+        child.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn parents() -> (Chromosome<usize>, Chromosome<usize>) {
+        let parent_a = (0..10).collect();
+        let parent_b = vec![9, 3, 7, 8, 2, 6, 5, 1, 4, 0].into_iter().collect();
+        (parent_a, parent_b)
+    }
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = PartiallyMappedCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        assert_eq!(child.genes(), &[0, 3, 1, 4, 2, 6, 5, 7, 8, 9]);
+    }
+
+    #[test]
+    fn children_are_permutations() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        for _ in 0..100 {
+            let child = PartiallyMappedCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+            let mut genes = child.genes().to_vec();
+            genes.sort_unstable();
+            assert_eq!(genes, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn identical_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent, _) = parents();
+
+        let child = PartiallyMappedCrossover::new().crossover(&mut rng, &parent, &parent);
+
+        assert_eq!(child, parent);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::*;
+use rand::Rng;
+
+// Counterpart of `SelfAdaptiveMutation`: genes are inherited from either
+// parent like in `UniformCrossover`, while step sizes are averaged, which
+// keeps them from fluctuating too wildly between generations
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelfAdaptiveCrossover;
+
+impl SelfAdaptiveCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CrossoverMethod for SelfAdaptiveCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let child: Chromosome = parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| if rng.gen_bool(0.5) { a } else { b })
+            .collect();
+
+        let strategy = match (parent_a.has_strategy(), parent_b.has_strategy()) {
+            (true, true) => parent_a
+                .strategy()
+                .iter()
+                .zip(parent_b.strategy())
+                .map(|(a, b)| 0.5 * (a + b))
+                .collect(),
+            (true, false) => parent_a.strategy().to_vec(),
+            (false, true) => parent_b.strategy().to_vec(),
+            (false, false) => Vec::new(),
+        };
+
+        child.with_strategy(strategy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn parents() -> (Chromosome, Chromosome) {
+        let parent_a: Chromosome = (1..=100).map(|n| n as f32).collect();
+        let parent_b: Chromosome = (1..=100).map(|n| -n as f32).collect();
+        (parent_a, parent_b)
+    }
+
+    #[test]
+    fn genes_are_inherited_from_either_parent() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = SelfAdaptiveCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        let diff_a = child
+            .iter()
+            .zip(parent_a.iter())
+            .filter(|(c, p)| c != p)
+            .count();
+        let diff_b = child
+            .iter()
+            .zip(parent_b.iter())
+            .filter(|(c, p)| c != p)
+            .count();
+
+        assert_eq!(diff_a, 49);
+        assert_eq!(diff_b, 51);
+    }
+
+    #[test]
+    fn step_sizes_are_averaged() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+        let parent_a = parent_a.with_strategy(vec![0.1; 100]);
+        let parent_b = parent_b.with_strategy(vec![0.3; 100]);
+
+        let child = SelfAdaptiveCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        approx::assert_relative_eq!(child.strategy(), vec![0.2; 100].as_slice());
+    }
+
+    #[test]
+    fn step_sizes_of_single_parent_are_kept() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+        let parent_b = parent_b.with_strategy(vec![0.3; 100]);
+
+        let child = SelfAdaptiveCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        approx::assert_relative_eq!(child.strategy(), vec![0.3; 100].as_slice());
+    }
+
+    #[test]
+    fn no_step_sizes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = SelfAdaptiveCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        assert!(!child.has_strategy());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::*;
+use rand::Rng;
+
+// SBX: mimics the spread of single-point crossover on binary strings for
+// real-valued genes. Child genes are placed symmetrically around the parents'
+// mean at a distance drawn from a polynomial distribution - the bigger the
+// distribution index `eta`, the closer the children stay to their parents.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulatedBinaryCrossover {
+    eta: f32,
+}
+
+impl SimulatedBinaryCrossover {
+    pub fn new(eta: f32) -> Self {
+        assert!(eta >= 0.0);
+        Self { eta }
+    }
+
+    // Spread factor: ratio between the distance of the children and the
+    // distance of the parents
+    fn spread(&self, rng: &mut dyn RngCore) -> f32 {
+        let u = rng.gen::<f32>();
+        let exponent = 1.0 / (self.eta + 1.0);
+
+        if u <= 0.5 {
+            (2.0 * u).powf(exponent)
+        } else {
+            (1.0 / (2.0 * (1.0 - u))).powf(exponent)
+        }
+    }
+}
+
+impl Default for SimulatedBinaryCrossover {
+    fn default() -> Self {
+        Self::new(15.0)
+    }
+}
+
+impl CrossoverMethod for SimulatedBinaryCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| {
+                let spread = self.spread(rng);
+                let mean = 0.5 * (a + b);
+                let offset = 0.5 * spread * (a - b);
+                // SBX yields two children per pair of parents; pick either
+                if rng.gen_bool(0.5) {
+                    mean + offset
+                } else {
+                    mean - offset
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn random_chromosome(rng: &mut dyn RngCore) -> Chromosome {
+        (0..20).map(|_| rng.gen_range(-10.0..10.0)).collect()
+    }
+
+    // `rng.gen::<f32>()` never gets closer to 1.0 than 2^-24, which bounds
+    // the spread factor from above
+    fn max_spread(eta: f32) -> f32 {
+        2.0f32.powi(23).powf(1.0 / (eta + 1.0))
+    }
+
+    #[test]
+    fn genes_stay_within_spread_interval() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        for eta in [0.0, 2.0, 15.0, 100.0] {
+            let method = SimulatedBinaryCrossover::new(eta);
+
+            for _ in 0..500 {
+                let parent_a = random_chromosome(&mut rng);
+                let parent_b = random_chromosome(&mut rng);
+                let child = method.crossover(&mut rng, &parent_a, &parent_b);
+
+                for ((&a, &b), &c) in parent_a.iter().zip(parent_b.iter()).zip(child.iter()) {
+                    let mean = 0.5 * (a + b);
+                    let radius = 0.5 * max_spread(eta) * (a - b).abs();
+                    assert!((c - mean).abs() <= radius * 1.001 + 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn contracts_and_expands_equally_often() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let method = SimulatedBinaryCrossover::new(2.0);
+        let parent_a: Chromosome = vec![0.0; 1000].into_iter().collect();
+        let parent_b: Chromosome = vec![1.0; 1000].into_iter().collect();
+
+        let child = method.crossover(&mut rng, &parent_a, &parent_b);
+
+        let inside = child.iter().filter(|&&c| (0.0..=1.0).contains(&c)).count();
+        assert_eq!(inside, 495);
+    }
+
+    #[test]
+    fn large_eta_stays_close_to_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let method = SimulatedBinaryCrossover::new(1000.0);
+        let parent_a: Chromosome = vec![0.0; 1000].into_iter().collect();
+        let parent_b: Chromosome = vec![1.0; 1000].into_iter().collect();
+
+        let child = method.crossover(&mut rng, &parent_a, &parent_b);
+
+        assert!(child.iter().all(|&c| c.min(1.0 - c).abs() < 0.02));
+    }
+
+    #[test]
+    fn identical_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent: Chromosome = vec![1.0, -2.0, 3.0].into_iter().collect();
+
+        let child = SimulatedBinaryCrossover::new(2.0).crossover(&mut rng, &parent, &parent);
+
+        assert_eq!(child, parent);
+    }
+}
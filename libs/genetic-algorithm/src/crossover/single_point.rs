@@ -0,0 +1,89 @@
+use crate::*;
+use rand::Rng;
+
+// Takes the genes before a random cut point from one parent and the rest from
+// the other, so runs of neighbouring genes (e.g. a neuron's weights) survive
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SinglePointCrossover;
+
+impl SinglePointCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<G> CrossoverMethod<G> for SinglePointCrossover
+where
+    G: Gene,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G> {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        if parent_a.len() < 2 {
+            return parent_a.clone();
+        }
+
+        // Cut somewhere strictly inside so both parents contribute
+        let point = rng.gen_range(1..parent_a.len());
+
+        parent_a
+            .iter()
+            .take(point)
+            .chain(parent_b.iter().skip(point))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn parents() -> (Chromosome, Chromosome) {
+        let parent_a = (1..=10).map(|n| n as f32).collect();
+        let parent_b = (1..=10).map(|n| -n as f32).collect();
+        (parent_a, parent_b)
+    }
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let (parent_a, parent_b) = parents();
+
+        let child = SinglePointCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        let expected: Chromosome = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, -9.0, -10.0]
+            .into_iter()
+            .collect();
+        assert_eq!(child, expected);
+    }
+
+    #[test]
+    fn single_gene() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0].into_iter().collect();
+        let parent_b: Chromosome = vec![-1.0].into_iter().collect();
+
+        let child = SinglePointCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        assert_eq!(child, parent_a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_lengths() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0].into_iter().collect();
+        let parent_b: Chromosome = vec![-1.0].into_iter().collect();
+
+        SinglePointCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+    }
+}
@@ -2,6 +2,7 @@ use crate::*;
 use rand::Rng;
 
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformCrossover;
 
 impl UniformCrossover {
@@ -10,13 +11,16 @@ impl UniformCrossover {
     }
 }
 
-impl CrossoverMethod for UniformCrossover {
+impl<G> CrossoverMethod<G> for UniformCrossover
+where
+    G: Gene,
+{
     fn crossover(
         &self,
         rng: &mut dyn RngCore,
-        parent_a: &Chromosome,
-        parent_b: &Chromosome,
-    ) -> Chromosome {
+        parent_a: &Chromosome<G>,
+        parent_b: &Chromosome<G>,
+    ) -> Chromosome<G> {
         assert_eq!(parent_a.len(), parent_b.len());
 
         let parent_a = parent_a.iter();
@@ -24,7 +28,8 @@ impl CrossoverMethod for UniformCrossover {
 
         parent_a
             .zip(parent_b)
-            .map(|(&a, &b)| if rng.gen_bool(0.5) { a } else { b })
+            .map(|(a, b)| if rng.gen_bool(0.5) { a } else { b })
+            .cloned()
             .collect()
     }
 }
@@ -0,0 +1,178 @@
+use crate::*;
+
+// Genetic diversity of a population, useful to spot premature convergence:
+// when all of these approach zero, every individual carries (nearly) the
+// same chromosome and crossover can't produce anything new anymore.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diversity {
+    mean_pairwise_distance: f32,
+    gene_variance: Vec<f32>,
+    centroid_distance: f32,
+}
+
+impl Diversity {
+    pub fn new<I>(population: &[I]) -> Self
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let len = population[0].chromosome().len();
+        // Copy all the genes into one contiguous row-major buffer, which is
+        // much friendlier to the cache in the quadratic loop below
+        let mut genes = Vec::with_capacity(population.len() * len);
+        for individual in population {
+            let chromosome = individual.chromosome();
+            assert_eq!(chromosome.len(), len);
+            genes.extend(chromosome.iter().map(Gene::as_f64));
+        }
+        let rows: Vec<_> = genes.chunks_exact(len.max(1)).collect();
+        let count = population.len() as f64;
+
+        let mut centroid = vec![0.0; len];
+        for row in &rows {
+            for (sum, gene) in centroid.iter_mut().zip(row.iter()) {
+                *sum += gene;
+            }
+        }
+        centroid.iter_mut().for_each(|sum| *sum /= count);
+
+        let mut gene_variance = vec![0.0; len];
+        let mut centroid_distance = 0.0;
+        for row in &rows {
+            let mut squared_distance = 0.0;
+            for ((variance, gene), mean) in gene_variance.iter_mut().zip(row.iter()).zip(&centroid)
+            {
+                let diff = gene - mean;
+                *variance += diff * diff;
+                squared_distance += diff * diff;
+            }
+            centroid_distance += squared_distance.sqrt();
+        }
+
+        // Every unordered pair once
+        let mut pairwise_distance = 0.0;
+        for (i, a) in rows.iter().enumerate() {
+            for b in &rows[i + 1..] {
+                pairwise_distance += euclidean_distance(a, b);
+            }
+        }
+        let pairs = count * (count - 1.0) / 2.0;
+
+        Self {
+            mean_pairwise_distance: if pairs > 0.0 {
+                (pairwise_distance / pairs) as f32
+            } else {
+                0.0
+            },
+            gene_variance: gene_variance
+                .into_iter()
+                .map(|variance| (variance / count) as f32)
+                .collect(),
+            centroid_distance: (centroid_distance / count) as f32,
+        }
+    }
+    // Average Euclidean distance between any two chromosomes
+    pub fn mean_pairwise_distance(&self) -> f32 {
+        self.mean_pairwise_distance
+    }
+    // Variance of each gene across the population
+    pub fn gene_variance(&self) -> &[f32] {
+        &self.gene_variance
+    }
+    pub fn mean_gene_variance(&self) -> f32 {
+        if self.gene_variance.is_empty() {
+            return 0.0;
+        }
+        self.gene_variance.iter().sum::<f32>() / self.gene_variance.len() as f32
+    }
+    // Average Euclidean distance between a chromosome and the population's
+    // centroid (gene-wise mean)
+    pub fn centroid_distance(&self) -> f32 {
+        self.centroid_distance
+    }
+}
+
+fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    #[test]
+    fn test() {
+        let population = vec![
+            individual(&[0.0, 0.0]),
+            individual(&[3.0, 4.0]),
+            individual(&[0.0, 4.0]),
+        ];
+
+        let diversity = Diversity::new(&population);
+
+        // (5 + 3 + 4) / 3
+        approx::assert_relative_eq!(diversity.mean_pairwise_distance(), 4.0);
+        approx::assert_relative_eq!(diversity.gene_variance(), &[2.0, 32.0 / 9.0][..]);
+        approx::assert_relative_eq!(diversity.mean_gene_variance(), 25.0 / 9.0);
+        // Centroid is (1, 8/3)
+        let expected = ((1.0f32 + 64.0 / 9.0).sqrt()
+            + (4.0f32 + 16.0 / 9.0).sqrt()
+            + (1.0f32 + 16.0 / 9.0).sqrt())
+            / 3.0;
+        approx::assert_relative_eq!(diversity.centroid_distance(), expected);
+    }
+
+    #[test]
+    fn converged_population() {
+        let population = vec![individual(&[1.0, 2.0, 3.0]); 10];
+
+        let diversity = Diversity::new(&population);
+
+        approx::assert_relative_eq!(diversity.mean_pairwise_distance(), 0.0);
+        approx::assert_relative_eq!(diversity.mean_gene_variance(), 0.0);
+        approx::assert_relative_eq!(diversity.centroid_distance(), 0.0);
+    }
+
+    #[test]
+    fn single_individual() {
+        let diversity = Diversity::new(&[individual(&[1.0, 2.0])]);
+
+        approx::assert_relative_eq!(diversity.mean_pairwise_distance(), 0.0);
+        approx::assert_relative_eq!(diversity.centroid_distance(), 0.0);
+    }
+
+    #[test]
+    fn large_population() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population: Vec<_> = (0..300)
+            .map(|_| {
+                let genes: Vec<f32> = (0..150).map(|_| rng.gen_range(-1.0..1.0)).collect();
+                individual(&genes)
+            })
+            .collect();
+
+        let diversity = Diversity::new(&population);
+
+        // Uniform distribution on [-1, 1) has a variance of 1/3, and the
+        // expected squared distance between two points is twice the summed
+        // variance
+        approx::assert_abs_diff_eq!(diversity.mean_gene_variance(), 1.0 / 3.0, epsilon = 0.01);
+        approx::assert_abs_diff_eq!(
+            diversity.mean_pairwise_distance(),
+            (2.0f32 * 150.0 / 3.0).sqrt(),
+            epsilon = 0.1
+        );
+    }
+}
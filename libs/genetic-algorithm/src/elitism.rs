@@ -0,0 +1,89 @@
+// How many of the fittest individuals are carried over into the next
+// generation unchanged
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Elitism {
+    Count(usize),
+    // Fraction of the population in [0.0, 1.0], rounded to the nearest count
+    Fraction(f32),
+}
+
+impl Elitism {
+    pub fn count(&self, population_len: usize) -> usize {
+        self.validate();
+        let count = match *self {
+            Self::Count(count) => count,
+            Self::Fraction(fraction) => (fraction * population_len as f32).round() as usize,
+        };
+        count.min(population_len)
+    }
+
+    // Panics on a fraction out of range, so a bad setting is caught when it's
+    // made rather than when the first generation gets bred
+    pub(crate) fn validate(&self) {
+        if let Self::Fraction(fraction) = *self {
+            assert!(
+                (0.0..=1.0).contains(&fraction),
+                "got elitism fraction {}, expected one in [0.0, 1.0]",
+                fraction
+            );
+        }
+    }
+}
+
+impl Default for Elitism {
+    fn default() -> Self {
+        Self::Count(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod count {
+        use super::*;
+
+        #[test]
+        fn count() {
+            assert_eq!(Elitism::Count(0).count(10), 0);
+            assert_eq!(Elitism::Count(3).count(10), 3);
+            assert_eq!(Elitism::Count(30).count(10), 10);
+        }
+
+        #[test]
+        fn fraction() {
+            assert_eq!(Elitism::Fraction(0.0).count(10), 0);
+            assert_eq!(Elitism::Fraction(0.25).count(10), 3);
+            assert_eq!(Elitism::Fraction(0.5).count(40), 20);
+            assert_eq!(Elitism::Fraction(1.0).count(10), 10);
+        }
+    }
+
+    mod validate {
+        use super::*;
+
+        #[test]
+        fn in_range() {
+            Elitism::Count(30).validate();
+            Elitism::Fraction(0.0).validate();
+            Elitism::Fraction(1.0).validate();
+        }
+
+        #[test]
+        #[should_panic(expected = "got elitism fraction 1.5")]
+        fn out_of_range() {
+            Elitism::Fraction(1.5).validate();
+        }
+
+        #[test]
+        #[should_panic(expected = "got elitism fraction NaN")]
+        fn nan() {
+            Elitism::Fraction(f32::NAN).validate();
+        }
+    }
+}
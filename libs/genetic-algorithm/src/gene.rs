@@ -0,0 +1,103 @@
+use std::fmt::Debug;
+
+// Anything a chromosome can be made of: real numbers for neural weights,
+// bits for feature selection, integers for scheduling, city indices for
+// routing and so on
+pub trait Gene: Clone + Debug + Send + Sync + 'static {
+    // Whether two genes should be considered equal; real-valued genes are
+    // compared approximately
+    fn gene_eq(&self, other: &Self) -> bool;
+
+    // Numeric value of the gene, used to measure distances between
+    // chromosomes and the diversity of a population
+    fn as_f64(&self) -> f64;
+}
+
+impl Gene for f32 {
+    fn gene_eq(&self, other: &Self) -> bool {
+        approx::relative_eq!(self, other)
+    }
+
+    fn as_f64(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl Gene for f64 {
+    fn gene_eq(&self, other: &Self) -> bool {
+        approx::relative_eq!(self, other)
+    }
+
+    fn as_f64(&self) -> f64 {
+        *self
+    }
+}
+
+impl Gene for bool {
+    fn gene_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn as_f64(&self) -> f64 {
+        if *self {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+macro_rules! impl_integer_gene {
+    ($($ty:ty),*) => {
+        $(
+            impl Gene for $ty {
+                fn gene_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+
+                fn as_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_gene!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod gene_eq {
+        use super::*;
+
+        #[test]
+        fn real() {
+            assert!(1.0f32.gene_eq(&(1.0 + f32::EPSILON / 2.0)));
+            assert!(!1.0f32.gene_eq(&1.1));
+            assert!(1.0f64.gene_eq(&1.0));
+        }
+
+        #[test]
+        fn exact() {
+            assert!(true.gene_eq(&true));
+            assert!(!true.gene_eq(&false));
+            assert!(3usize.gene_eq(&3));
+            assert!(!(-3i32).gene_eq(&3));
+        }
+    }
+
+    mod as_f64 {
+        use super::*;
+
+        #[test]
+        fn test() {
+            assert_eq!(0.5f32.as_f64(), 0.5);
+            assert_eq!(true.as_f64(), 1.0);
+            assert_eq!(false.as_f64(), 0.0);
+            assert_eq!((-7i64).as_f64(), -7.0);
+            assert_eq!(7u8.as_f64(), 7.0);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::*;
+
+// The fittest distinct individuals ever seen, so that an excellent
+// chromosome can't be lost to a single bad generation. Optionally puts some
+// of them back into every new generation.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HallOfFame<G = f32> {
+    capacity: usize,
+    // Champions re-injected into every new generation
+    reinject: usize,
+    // Fittest first
+    champions: Vec<Champion<G>>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Champion<G = f32> {
+    chromosome: Chromosome<G>,
+    fitness: f32,
+    generation: usize,
+}
+
+impl<G> Champion<G> {
+    pub fn chromosome(&self) -> &Chromosome<G> {
+        &self.chromosome
+    }
+    pub fn fitness(&self) -> f32 {
+        self.fitness
+    }
+    // Generation the champion was seen in with this fitness
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+impl<G> PartialEq for Champion<G>
+where
+    G: Gene,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.chromosome == other.chromosome
+            && self.fitness == other.fitness
+            && self.generation == other.generation
+    }
+}
+
+impl<G> HallOfFame<G>
+where
+    G: Gene,
+{
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self {
+            capacity,
+            reinject: 0,
+            champions: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn with_reinjection(mut self, count: usize) -> Self {
+        assert!(count <= self.capacity);
+        self.reinject = count;
+        self
+    }
+
+    // Champions put back into every new generation, once there are enough
+    pub fn reinjection(&self) -> usize {
+        self.reinject
+    }
+
+    pub fn champions(&self) -> &[Champion<G>] {
+        &self.champions
+    }
+
+    pub fn best(&self) -> Option<&Champion<G>> {
+        self.champions.first()
+    }
+
+    pub fn len(&self) -> usize {
+        self.champions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.champions.is_empty()
+    }
+
+    // Considers every (evaluated) individual of the population for a place
+    pub fn update<I>(&mut self, population: &[I], generation: usize)
+    where
+        I: Individual<Gene = G>,
+    {
+        for individual in population {
+            let fitness = individual.fitness();
+            if self.champions.len() == self.capacity
+                && fitness <= self.champions.last().unwrap().fitness
+            {
+                continue;
+            }
+
+            // Chromosomes are only ever listed once, with the best fitness
+            // they've managed so far
+            let chromosome = individual.chromosome();
+            if let Some(index) = self
+                .champions
+                .iter()
+                .position(|champion| champion.chromosome == *chromosome)
+            {
+                if fitness <= self.champions[index].fitness {
+                    continue;
+                }
+                self.champions.remove(index);
+            }
+
+            let index = self
+                .champions
+                .partition_point(|champion| champion.fitness >= fitness);
+            self.champions.insert(
+                index,
+                Champion {
+                    chromosome: chromosome.clone(),
+                    fitness,
+                    generation,
+                },
+            );
+            self.champions.truncate(self.capacity);
+        }
+    }
+
+    // Replaces the last members of a freshly evolved population (elites come
+    // first, so they're kept) with copies of the fittest champions
+    // Returns how many champions were put back, at the end of the population
+    pub fn reinject<I>(&self, population: &mut [I]) -> usize
+    where
+        I: Individual<Gene = G>,
+    {
+        let count = self.reinjected(population.len());
+        let start = population.len() - count;
+
+        for (slot, champion) in population[start..].iter_mut().zip(&self.champions) {
+            *slot = I::create(champion.chromosome.clone());
+        }
+        count
+    }
+
+    // How many champions `reinject` puts into a population of the given size
+    pub(crate) fn reinjected(&self, population_len: usize) -> usize {
+        self.reinject.min(self.champions.len()).min(population_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    fn fitnesses(hall_of_fame: &HallOfFame) -> Vec<f32> {
+        hall_of_fame
+            .champions()
+            .iter()
+            .map(Champion::fitness)
+            .collect()
+    }
+
+    #[test]
+    fn keeps_fittest_ever_seen() {
+        let mut hall_of_fame = HallOfFame::new(3);
+
+        hall_of_fame.update(&[individual(&[1.0]), individual(&[5.0])], 0);
+        hall_of_fame.update(&[individual(&[2.0]), individual(&[4.0])], 1);
+        hall_of_fame.update(&[individual(&[0.0]), individual(&[3.0])], 2);
+
+        assert_eq!(fitnesses(&hall_of_fame), vec![5.0, 4.0, 3.0]);
+        let generations: Vec<_> = hall_of_fame
+            .champions()
+            .iter()
+            .map(Champion::generation)
+            .collect();
+        assert_eq!(generations, vec![0, 1, 2]);
+        assert_eq!(hall_of_fame.best().unwrap().fitness(), 5.0);
+    }
+
+    #[test]
+    fn champions_are_distinct() {
+        let mut hall_of_fame = HallOfFame::new(3);
+
+        hall_of_fame.update(&[individual(&[5.0]), individual(&[5.0])], 0);
+        hall_of_fame.update(&[individual(&[5.0]), individual(&[1.0])], 1);
+
+        assert_eq!(fitnesses(&hall_of_fame), vec![5.0, 1.0]);
+        assert_eq!(hall_of_fame.best().unwrap().generation(), 0);
+    }
+
+    #[test]
+    fn ties_keep_the_older_champion() {
+        let mut hall_of_fame = HallOfFame::new(1);
+
+        hall_of_fame.update(&[individual(&[1.0, 2.0])], 0);
+        hall_of_fame.update(&[individual(&[2.0, 1.0])], 1);
+
+        assert_eq!(hall_of_fame.best().unwrap().generation(), 0);
+    }
+
+    #[test]
+    fn reinject() {
+        let mut hall_of_fame = HallOfFame::new(3).with_reinjection(2);
+        hall_of_fame.update(
+            &[individual(&[9.0]), individual(&[8.0]), individual(&[7.0])],
+            0,
+        );
+        let mut population = vec![individual(&[1.0]), individual(&[2.0]), individual(&[3.0])];
+
+        hall_of_fame.reinject(&mut population);
+
+        assert_eq!(
+            population,
+            vec![individual(&[1.0]), individual(&[9.0]), individual(&[8.0])]
+        );
+    }
+
+    #[test]
+    fn reinject_nothing_by_default() {
+        let mut hall_of_fame = HallOfFame::new(3);
+        hall_of_fame.update(&[individual(&[9.0])], 0);
+        let mut population = vec![individual(&[1.0])];
+
+        hall_of_fame.reinject(&mut population);
+
+        assert_eq!(population, vec![individual(&[1.0])]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut hall_of_fame = HallOfFame::new(2);
+        hall_of_fame.update(&[individual(&[1.0, 2.0]), individual(&[3.0, 4.0])], 7);
+
+        let json = serde_json::to_string(&hall_of_fame).unwrap();
+        let actual: HallOfFame = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(actual.champions(), hall_of_fame.champions());
+    }
+}
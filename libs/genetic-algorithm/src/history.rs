@@ -0,0 +1,416 @@
+use crate::*;
+use std::fmt::Write;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenerationRecord<G = f32> {
+    generation: usize,
+    statistics: Statistics<G>,
+    diversity: Option<Diversity>,
+    duration: Duration,
+}
+
+impl<G> GenerationRecord<G> {
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+    pub fn statistics(&self) -> &Statistics<G> {
+        &self.statistics
+    }
+    pub fn diversity(&self) -> Option<&Diversity> {
+        self.diversity.as_ref()
+    }
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+// Per-generation log of an evolutionary run
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunHistory<G = f32> {
+    records: Vec<GenerationRecord<G>>,
+}
+
+const CSV_HEADER: &str = "generation,duration_secs,min_fitness,max_fitness,avg_fitness,\
+median_fitness,std_dev_fitness,lower_quartile,upper_quartile,best_index,\
+mean_pairwise_distance,mean_gene_variance,centroid_distance";
+
+impl<G> RunHistory<G>
+where
+    G: Gene,
+{
+    pub fn new() -> Self {
+        Self {
+            records: Vec::new(),
+        }
+    }
+
+    pub fn record(
+        &mut self,
+        statistics: Statistics<G>,
+        diversity: Option<Diversity>,
+        duration: Duration,
+    ) {
+        self.records.push(GenerationRecord {
+            generation: self.records.len(),
+            statistics,
+            diversity,
+            duration,
+        });
+    }
+
+    pub fn records(&self) -> &[GenerationRecord<G>] {
+        &self.records
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn last(&self) -> Option<&GenerationRecord<G>> {
+        self.records.last()
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.records.iter().map(|record| record.duration).sum()
+    }
+
+    // Highest fitness seen in any generation so far
+    pub fn best_so_far(&self) -> Option<f32> {
+        self.best_record()
+            .map(|record| record.statistics.max_fitness())
+    }
+
+    // Generation in which the best fitness so far was first reached
+    pub fn best_generation(&self) -> Option<usize> {
+        self.best_record().map(|record| record.generation)
+    }
+
+    // Number of generations recorded since the best fitness last improved
+    pub fn stagnation(&self) -> usize {
+        self.best_generation()
+            .map_or(0, |generation| self.records.len() - 1 - generation)
+    }
+
+    fn best_record(&self) -> Option<&GenerationRecord<G>> {
+        self.records.iter().reduce(|best, record| {
+            if record.statistics.max_fitness() > best.statistics.max_fitness() {
+                record
+            } else {
+                best
+            }
+        })
+    }
+
+    // One line per generation; diversity columns are left empty for
+    // generations recorded without it
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+
+        for record in &self.records {
+            let stats = &record.statistics;
+            write!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{},",
+                record.generation,
+                record.duration.as_secs_f64(),
+                stats.min_fitness(),
+                stats.max_fitness(),
+                stats.avg_fitness(),
+                stats.median_fitness(),
+                stats.std_dev_fitness(),
+                stats.lower_quartile(),
+                stats.upper_quartile(),
+                stats.best_index(),
+            )
+            .unwrap();
+            if let Some(diversity) = &record.diversity {
+                write!(
+                    csv,
+                    "{},{},{}",
+                    diversity.mean_pairwise_distance(),
+                    diversity.mean_gene_variance(),
+                    diversity.centroid_distance(),
+                )
+                .unwrap();
+            } else {
+                csv.push_str(",,");
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<G> RunHistory<G>
+where
+    G: Gene,
+{
+    // Array with one object per generation, using the same field names as
+    // the CSV columns and `null` for anything missing or non-finite
+    pub fn to_json(&self) -> String {
+        let rows: Vec<_> = self.records.iter().map(JsonRow::new).collect();
+        serde_json::to_string(&rows).expect("couldn't serialize the history")
+    }
+}
+
+impl<G> Default for RunHistory<G>
+where
+    G: Gene,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Times generations for the history. There's no clock on
+// wasm32-unknown-unknown - `Instant::now()` panics there - so generations
+// are recorded as taking no time at all.
+#[derive(Clone, Copy, Debug)]
+pub struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Instant,
+}
+
+impl Stopwatch {
+    pub fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: Instant::now(),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn elapsed(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonRow {
+    generation: usize,
+    duration_secs: f64,
+    min_fitness: f32,
+    max_fitness: f32,
+    avg_fitness: f32,
+    median_fitness: f32,
+    std_dev_fitness: f32,
+    lower_quartile: f32,
+    upper_quartile: f32,
+    best_index: usize,
+    mean_pairwise_distance: Option<f32>,
+    mean_gene_variance: Option<f32>,
+    centroid_distance: Option<f32>,
+}
+
+#[cfg(feature = "serde")]
+impl JsonRow {
+    fn new<G>(record: &GenerationRecord<G>) -> Self
+    where
+        G: Gene,
+    {
+        let stats = &record.statistics;
+        let diversity = record.diversity.as_ref();
+        Self {
+            generation: record.generation,
+            duration_secs: record.duration.as_secs_f64(),
+            min_fitness: stats.min_fitness(),
+            max_fitness: stats.max_fitness(),
+            avg_fitness: stats.avg_fitness(),
+            median_fitness: stats.median_fitness(),
+            std_dev_fitness: stats.std_dev_fitness(),
+            lower_quartile: stats.lower_quartile(),
+            upper_quartile: stats.upper_quartile(),
+            best_index: stats.best_index(),
+            mean_pairwise_distance: diversity.map(Diversity::mean_pairwise_distance),
+            mean_gene_variance: diversity.map(Diversity::mean_gene_variance),
+            centroid_distance: diversity.map(Diversity::centroid_distance),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn individual(fitness: f32) -> TestIndividual {
+        TestIndividual::create(vec![fitness].into_iter().collect())
+    }
+
+    fn history(max_fitnesses: &[f32]) -> RunHistory {
+        let mut history = RunHistory::new();
+        for &max_fitness in max_fitnesses {
+            let population = vec![individual(0.0), individual(max_fitness)];
+            history.record(
+                Statistics::new(&population),
+                Some(Diversity::new(&population)),
+                Duration::from_millis(500),
+            );
+        }
+        history
+    }
+
+    mod record {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let history = history(&[1.0, 2.0]);
+
+            assert_eq!(history.len(), 2);
+            assert_eq!(history.records()[0].generation(), 0);
+            assert_eq!(history.records()[1].generation(), 1);
+            assert_eq!(history.last().unwrap().statistics().max_fitness(), 2.0);
+            assert_eq!(history.total_duration(), Duration::from_secs(1));
+        }
+    }
+
+    mod best_so_far {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let history = history(&[1.0, 3.0, 2.0, 3.0]);
+
+            assert_eq!(history.best_so_far(), Some(3.0));
+            assert_eq!(history.best_generation(), Some(1));
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(history(&[]).best_so_far(), None);
+            assert_eq!(history(&[]).best_generation(), None);
+        }
+    }
+
+    mod stagnation {
+        use super::*;
+
+        #[test]
+        fn improving() {
+            assert_eq!(history(&[1.0, 2.0, 3.0]).stagnation(), 0);
+        }
+
+        #[test]
+        fn stagnating() {
+            assert_eq!(history(&[1.0, 3.0, 2.0, 3.0]).stagnation(), 2);
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(history(&[]).stagnation(), 0);
+        }
+    }
+
+    mod to_csv {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut history = history(&[2.0]);
+            history.record(
+                Statistics::new(&[individual(4.0)]),
+                None,
+                Duration::from_millis(250),
+            );
+
+            let csv = history.to_csv();
+            let lines: Vec<_> = csv.lines().collect();
+
+            assert_eq!(lines.len(), 3);
+            assert_eq!(lines[0], CSV_HEADER);
+            assert_eq!(lines[1], "0,0.5,0,2,1,1,1,0.5,1.5,1,2,1,1");
+            assert_eq!(lines[2], "1,0.25,4,4,4,4,0,4,4,0,,,");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod to_json {
+        use super::*;
+        use serde_json::json;
+
+        fn to_json(history: &RunHistory) -> serde_json::Value {
+            serde_json::from_str(&history.to_json()).unwrap()
+        }
+
+        #[test]
+        fn test() {
+            let mut history = history(&[2.0]);
+            history.record(
+                Statistics::new(&[individual(4.0)]),
+                None,
+                Duration::from_millis(250),
+            );
+
+            assert_eq!(
+                to_json(&history),
+                json!([
+                    {
+                        "generation": 0,
+                        "duration_secs": 0.5,
+                        "min_fitness": 0.0,
+                        "max_fitness": 2.0,
+                        "avg_fitness": 1.0,
+                        "median_fitness": 1.0,
+                        "std_dev_fitness": 1.0,
+                        "lower_quartile": 0.5,
+                        "upper_quartile": 1.5,
+                        "best_index": 1,
+                        "mean_pairwise_distance": 2.0,
+                        "mean_gene_variance": 1.0,
+                        "centroid_distance": 1.0
+                    },
+                    {
+                        "generation": 1,
+                        "duration_secs": 0.25,
+                        "min_fitness": 4.0,
+                        "max_fitness": 4.0,
+                        "avg_fitness": 4.0,
+                        "median_fitness": 4.0,
+                        "std_dev_fitness": 0.0,
+                        "lower_quartile": 4.0,
+                        "upper_quartile": 4.0,
+                        "best_index": 0,
+                        "mean_pairwise_distance": null,
+                        "mean_gene_variance": null,
+                        "centroid_distance": null
+                    }
+                ])
+            );
+        }
+
+        #[test]
+        fn non_finite() {
+            let mut history = RunHistory::new();
+            history.record(
+                Statistics::new(&[individual(f32::INFINITY)]),
+                None,
+                Duration::ZERO,
+            );
+
+            assert_eq!(to_json(&history)[0]["max_fitness"], json!(null));
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(history(&[]).to_json(), "[]");
+        }
+    }
+}
@@ -1,9 +1,24 @@
 use crate::*;
 
 pub trait Individual {
+    type Gene: Gene;
+
     fn fitness(&self) -> f32;
-    fn chromosome(&self) -> &Chromosome;
-    fn create(chromosome: Chromosome) -> Self;
+    fn chromosome(&self) -> &Chromosome<Self::Gene>;
+    fn create(chromosome: Chromosome<Self::Gene>) -> Self;
+
+    // Optional description of how the individual behaves (e.g. how fast and
+    // how much an animal turns), used instead of its chromosome to tell how
+    // similar two individuals are
+    fn behaviour(&self) -> Option<Vec<f32>> {
+        None
+    }
+}
+
+// Individual judged by several, possibly conflicting objectives instead of a
+// single fitness; every objective is maximized
+pub trait MultiObjectiveIndividual: Individual {
+    fn objectives(&self) -> Vec<f32>;
 }
 
 #[cfg(test)]
@@ -22,6 +37,8 @@ impl TestIndividual {
 
 #[cfg(test)]
 impl Individual for TestIndividual {
+    type Gene = f32;
+
     fn fitness(&self) -> f32 {
         match self {
             Self::WithChromosome { chromosome } => chromosome.iter().sum(),
@@ -40,3 +57,11 @@ impl Individual for TestIndividual {
         Self::WithChromosome { chromosome }
     }
 }
+
+// Every gene is an objective of its own
+#[cfg(test)]
+impl MultiObjectiveIndividual for TestIndividual {
+    fn objectives(&self) -> Vec<f32> {
+        self.chromosome().iter().copied().collect()
+    }
+}
@@ -0,0 +1,402 @@
+use crate::*;
+
+// Which islands send their migrants where
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Topology {
+    // Island `i` sends to island `i + 1`, the last one back to the first
+    Ring,
+    // Every island sends to every other island
+    FullyConnected,
+    // Every island sends to another island picked at random each time
+    Random,
+}
+
+// Several sub-populations evolving side by side, each with its own
+// `GeneticAlgorithm`, which every few generations send copies of their
+// fittest individuals to each other. Keeps diversity higher than one big
+// population would, since each island can drift towards its own optimum.
+pub struct IslandModel<S, G = f32> {
+    islands: Vec<GeneticAlgorithm<S, G>>,
+    topology: Topology,
+    // Generations between two migrations
+    interval: usize,
+    // Individuals every island's population is made of
+    island_size: usize,
+    // Individuals sent by each island per migration
+    migrants: usize,
+    generation: usize,
+}
+
+impl<S, G> IslandModel<S, G>
+where
+    S: SelectionMethod,
+    G: Gene,
+{
+    pub fn new(
+        islands: Vec<GeneticAlgorithm<S, G>>,
+        topology: Topology,
+        island_size: usize,
+    ) -> Self {
+        assert!(!islands.is_empty());
+        assert!(island_size > 0);
+
+        Self {
+            islands,
+            topology,
+            interval: 10,
+            island_size,
+            migrants: 0,
+            generation: 0,
+        }
+        .with_migrants(1)
+    }
+
+    pub fn with_interval(mut self, interval: usize) -> Self {
+        assert!(interval > 0);
+        self.interval = interval;
+        self
+    }
+
+    // An island can take migrants from every other island at once (except in
+    // a ring), and all of them have to fit in place of its offspring
+    pub fn with_migrants(mut self, migrants: usize) -> Self {
+        let senders = match self.topology {
+            Topology::Ring => (self.islands.len() - 1).min(1),
+            Topology::FullyConnected | Topology::Random => self.islands.len() - 1,
+        };
+        let offspring = self
+            .islands
+            .iter()
+            .map(|island| {
+                let elites = island.elitism.count(self.island_size);
+                let champions = island
+                    .hall_of_fame
+                    .as_ref()
+                    .map_or(0, HallOfFame::reinjection);
+                self.island_size.saturating_sub(elites + champions)
+            })
+            .min()
+            .unwrap();
+        assert!(
+            migrants * senders <= offspring,
+            "got {} migrants from {} islands, but an island breeds only {} offspring",
+            migrants,
+            senders,
+            offspring
+        );
+        self.migrants = migrants;
+        self
+    }
+
+    pub fn islands(&self) -> &[GeneticAlgorithm<S, G>] {
+        &self.islands
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // Evolves every island's population with its own algorithm, then, on
+    // migration generations, replaces the last offspring of each receiving
+    // island (elites and re-injected champions are kept) with copies of the
+    // fittest individuals of the sending islands
+    pub fn evolve<I>(
+        &mut self,
+        rng: &mut dyn RngCore,
+        populations: &[Vec<I>],
+    ) -> (Vec<Vec<I>>, Vec<Statistics<G>>)
+    where
+        I: Individual<Gene = G>,
+    {
+        assert_eq!(populations.len(), self.islands.len());
+        assert!(
+            populations
+                .iter()
+                .all(|population| population.len() == self.island_size),
+            "got an island population of the wrong size"
+        );
+
+        let (mut evolved, stats): (Vec<_>, Vec<_>) = self
+            .islands
+            .iter_mut()
+            .zip(populations)
+            .map(|(island, population)| island.evolve(rng, population))
+            .unzip();
+
+        self.generation += 1;
+        if self.generation.is_multiple_of(self.interval) {
+            self.migrate(rng, populations, &mut evolved);
+        }
+
+        (evolved, stats)
+    }
+
+    fn migrate<I>(&mut self, rng: &mut dyn RngCore, populations: &[Vec<I>], evolved: &mut [Vec<I>])
+    where
+        I: Individual<Gene = G>,
+    {
+        let island_count = populations.len();
+        if island_count < 2 || self.migrants == 0 {
+            return;
+        }
+
+        // Only the current generation has been evaluated, so that's where
+        // the migrants come from
+        let mut incoming: Vec<Vec<&Chromosome<G>>> = vec![Vec::new(); island_count];
+        for (source, population) in populations.iter().enumerate() {
+            let mut ranked: Vec<_> = population.iter().collect();
+            ranked.sort_by(|a, b| b.fitness().total_cmp(&a.fitness()));
+            let migrants = ranked
+                .into_iter()
+                .take(self.migrants)
+                .map(|individual| individual.chromosome());
+
+            let targets = match self.topology {
+                Topology::Ring => vec![(source + 1) % island_count],
+                Topology::FullyConnected => (0..island_count).filter(|&i| i != source).collect(),
+                Topology::Random => {
+                    // Skip over the source so it never sends to itself
+                    vec![(source + rng.gen_range(1..island_count)) % island_count]
+                }
+            };
+
+            for migrant in migrants {
+                for &target in &targets {
+                    incoming[target].push(migrant);
+                }
+            }
+        }
+
+        for ((island, population), migrants) in self.islands.iter_mut().zip(evolved).zip(incoming) {
+            island.receive_migrants(population, &migrants);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    // Mutation-free islands which only ever reshuffle their own genes, so
+    // any foreign gene must have arrived through migration
+    fn model(islands: usize, topology: Topology) -> IslandModel<TournamentSelection> {
+        let islands = (0..islands)
+            .map(|_| {
+                GeneticAlgorithm::new(
+                    TournamentSelection::new(2),
+                    UniformCrossover::new(),
+                    GaussianMethod::new(0.0, 0.0),
+                )
+            })
+            .collect();
+
+        IslandModel::new(islands, topology, 4).with_interval(2)
+    }
+
+    // Island `i` is made of genes equal to `i` and its best individual has
+    // genes equal to `i + 0.5`
+    fn populations(islands: usize) -> Vec<Vec<TestIndividual>> {
+        (0..islands)
+            .map(|i| {
+                let i = i as f32;
+                vec![
+                    individual(&[i, i]),
+                    individual(&[i + 0.5, i + 0.5]),
+                    individual(&[i, i]),
+                    individual(&[i, i]),
+                ]
+            })
+            .collect()
+    }
+
+    fn received(populations: &[Vec<TestIndividual>], island: usize) -> Vec<f32> {
+        let mut received: Vec<_> = populations[island]
+            .iter()
+            .flat_map(|individual| individual.chromosome().iter().copied())
+            .filter(|&gene| gene.floor() != island as f32)
+            .collect();
+        received.sort_by(f32::total_cmp);
+        received.dedup();
+        received
+    }
+
+    #[test]
+    fn migrates_every_interval() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = model(3, Topology::Ring);
+
+        let (populations, stats) = model.evolve(&mut rng, &populations(3));
+        assert_eq!(stats.len(), 3);
+        assert!((0..3).all(|island| received(&populations, island).is_empty()));
+
+        let (populations, _) = model.evolve(&mut rng, &populations);
+        assert_eq!(model.generation(), 2);
+        assert!((0..3).all(|island| !received(&populations, island).is_empty()));
+    }
+
+    #[test]
+    fn ring() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = model(3, Topology::Ring).with_interval(1);
+
+        let (populations, _) = model.evolve(&mut rng, &populations(3));
+
+        assert_eq!(received(&populations, 0), vec![2.5]);
+        assert_eq!(received(&populations, 1), vec![0.5]);
+        assert_eq!(received(&populations, 2), vec![1.5]);
+    }
+
+    #[test]
+    fn fully_connected() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = model(3, Topology::FullyConnected).with_interval(1);
+
+        let (populations, _) = model.evolve(&mut rng, &populations(3));
+
+        assert_eq!(received(&populations, 0), vec![1.5, 2.5]);
+        assert_eq!(received(&populations, 1), vec![0.5, 2.5]);
+        assert_eq!(received(&populations, 2), vec![0.5, 1.5]);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = model(3, Topology::Random).with_interval(1);
+
+        let (populations, _) = model.evolve(&mut rng, &populations(3));
+
+        let all: Vec<_> = (0..3)
+            .flat_map(|island| received(&populations, island))
+            .collect();
+        assert_eq!(all.len(), 3);
+        for island in 0..3 {
+            assert!(!received(&populations, island).contains(&(island as f32 + 0.5)));
+        }
+    }
+
+    #[test]
+    fn keeps_population_sizes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = model(4, Topology::FullyConnected)
+            .with_interval(1)
+            .with_migrants(1);
+
+        let (populations, _) = model.evolve(&mut rng, &populations(4));
+
+        assert!(populations.iter().all(|population| population.len() == 4));
+    }
+
+    #[test]
+    fn single_island() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = model(1, Topology::Random).with_interval(1);
+
+        let (populations, _) = model.evolve(&mut rng, &populations(1));
+
+        assert!(received(&populations, 0).is_empty());
+    }
+
+    #[test]
+    fn as_many_migrants_as_fit() {
+        model(3, Topology::FullyConnected).with_migrants(2);
+        model(3, Topology::Ring).with_migrants(4);
+        model(1, Topology::Random).with_migrants(100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_migrants() {
+        // Two islands sending three each to an island of four
+        model(3, Topology::FullyConnected).with_migrants(3);
+    }
+
+    #[test]
+    fn keeps_champions() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let islands = (0..2)
+            .map(|_| {
+                GeneticAlgorithm::new(
+                    TournamentSelection::new(2),
+                    UniformCrossover::new(),
+                    GaussianMethod::new(0.0, 0.0),
+                )
+                .with_hall_of_fame(HallOfFame::new(1).with_reinjection(1))
+            })
+            .collect();
+        let mut model = IslandModel::new(islands, Topology::Ring, 4).with_interval(1);
+
+        let (populations, _) = model.evolve(&mut rng, &populations(2));
+
+        // Each island's own champion stays last, the migrant takes the slot
+        // before it
+        assert_eq!(populations[0][3], individual(&[0.5, 0.5]));
+        assert_eq!(populations[0][2], individual(&[1.5, 1.5]));
+        assert_eq!(populations[1][3], individual(&[1.5, 1.5]));
+        assert_eq!(populations[1][2], individual(&[0.5, 0.5]));
+    }
+
+    #[test]
+    fn tells_observers_about_migrants() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let lineage = Rc::new(RefCell::new(Lineage::new()));
+        let islands = vec![
+            GeneticAlgorithm::new(
+                TournamentSelection::new(2),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.0, 0.0),
+            )
+            .with_observer(lineage.clone()),
+            GeneticAlgorithm::new(
+                TournamentSelection::new(2),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.0, 0.0),
+            ),
+        ];
+        let mut model = IslandModel::new(islands, Topology::Ring, 4).with_interval(1);
+
+        model.evolve(&mut rng, &populations(2));
+
+        let lineage = lineage.borrow();
+        assert_eq!(lineage.generations()[0][3], Parentage::Migrant);
+        assert!(lineage.generations()[0][..3]
+            .iter()
+            .all(|parentage| matches!(parentage, Parentage::Bred { .. })));
+    }
+
+    #[test]
+    #[should_panic]
+    fn more_migrants_than_offspring() {
+        // Three elites leave a single offspring to replace
+        let islands = (0..2)
+            .map(|_| {
+                GeneticAlgorithm::new(
+                    TournamentSelection::new(2),
+                    UniformCrossover::new(),
+                    GaussianMethod::new(0.0, 0.0),
+                )
+                .with_elitism(Elitism::Count(3))
+            })
+            .collect();
+
+        IslandModel::new(islands, Topology::Ring, 4).with_migrants(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_population_size() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut populations = populations(3);
+        populations[1].pop();
+
+        model(3, Topology::Ring).evolve(&mut rng, &populations);
+    }
+}
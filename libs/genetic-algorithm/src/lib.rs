@@ -1,60 +1,429 @@
+#[cfg(feature = "serde")]
+pub use self::checkpoint::*;
 pub use self::{
-    chromosome::*, crossover::*, individual::*, mutation::*, selection::*, statistics::*,
+    builder::*, chromosome::*, cma_es::*, config::*, crossover::*, diversity::*, elitism::*,
+    gene::*, hall_of_fame::*, history::*, individual::*, island::*, mutation::*, nsga2::*,
+    observer::*, pareto::*, selection::*, statistics::*, steady_state::*, termination::*,
+    transform::*,
 };
 
 use rand::seq::SliceRandom;
 use rand::{Rng, RngCore};
+use std::collections::HashMap;
+use std::time::Duration;
 
+mod builder;
+#[cfg(feature = "serde")]
+mod checkpoint;
 mod chromosome;
+mod cma_es;
+mod config;
 mod crossover;
+mod diversity;
+mod elitism;
+mod gene;
+mod hall_of_fame;
+mod history;
 mod individual;
+mod island;
 mod mutation;
+mod nsga2;
+mod observer;
+mod pareto;
 mod selection;
 mod statistics;
+mod steady_state;
+mod termination;
+mod transform;
 
-pub struct GeneticAlgorithm<S> {
+pub struct GeneticAlgorithm<S, G = f32> {
     selection_method: S,
-    crossover_method: Box<dyn CrossoverMethod>,
-    mutation_method: Box<dyn MutationMethod>,
+    crossover_method: Box<dyn CrossoverMethod<G>>,
+    mutation_method: Box<dyn MutationMethod<G>>,
+    elitism: Elitism,
+    fitness_transforms: Vec<Box<dyn FitnessTransform>>,
+    hall_of_fame: Option<HallOfFame<G>>,
+    history: Option<RunHistory<G>>,
+    observers: Vec<Box<dyn Observer<G>>>,
+    // Generations evolved so far
+    generation: usize,
+    // What `GeneticAlgorithmConfig::build` put the algorithm together from
+    config: Option<GeneticAlgorithmConfig>,
 }
 
-impl<S> GeneticAlgorithm<S>
+impl<S, G> GeneticAlgorithm<S, G>
 where
     S: SelectionMethod,
+    G: Gene,
 {
     pub fn new(
         selection_method: S,
-        crossover_method: impl CrossoverMethod + 'static,
-        mutation_method: impl MutationMethod + 'static,
+        crossover_method: impl CrossoverMethod<G> + 'static,
+        mutation_method: impl MutationMethod<G> + 'static,
     ) -> Self {
         Self {
             selection_method,
             crossover_method: Box::new(crossover_method),
             mutation_method: Box::new(mutation_method),
+            elitism: Elitism::default(),
+            fitness_transforms: Vec::new(),
+            hall_of_fame: None,
+            history: None,
+            observers: Vec::new(),
+            generation: 0,
+            config: None,
         }
     }
 
-    pub fn evolve<I>(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics)
+    pub fn with_elitism(mut self, elitism: Elitism) -> Self {
+        elitism.validate();
+        self.elitism = elitism;
+        self
+    }
+
+    // Transforms are applied in the order they were added
+    pub fn with_fitness_transform(mut self, transform: impl FitnessTransform + 'static) -> Self {
+        self.fitness_transforms.push(Box::new(transform));
+        self
+    }
+
+    pub fn with_hall_of_fame(mut self, hall_of_fame: HallOfFame<G>) -> Self {
+        self.hall_of_fame = Some(hall_of_fame);
+        self
+    }
+
+    pub fn hall_of_fame(&self) -> Option<&HallOfFame<G>> {
+        self.hall_of_fame.as_ref()
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // Keep a log of every generation's statistics, diversity and the time it
+    // took to evolve it
+    pub fn with_history(mut self) -> Self {
+        self.history = Some(RunHistory::new());
+        self
+    }
+
+    pub fn history(&self) -> Option<&RunHistory<G>> {
+        self.history.as_ref()
+    }
+
+    // Observers are notified in the order they were added
+    pub fn with_observer(mut self, observer: impl Observer<G> + 'static) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    // With the `parallel` feature every child is bred from its own RNG stream,
+    // so the same seed leads to a different population than without it
+    pub fn evolve<I>(&mut self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics<G>)
     where
-        I: Individual,
+        I: Individual<Gene = G>,
+    {
+        self.evolve_measured(rng, population, None)
+    }
+
+    // `evolve` for when the diversity of `population` has already been
+    // measured, so that the history doesn't have to do it again
+    fn evolve_measured<I>(
+        &mut self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        diversity: Option<Diversity>,
+    ) -> (Vec<I>, Statistics<G>)
+    where
+        I: Individual<Gene = G>,
     {
         assert!(!population.is_empty());
-        let new_population = (0..population.len())
-            .map(|_| {
+        let start = self.history.is_some().then(Stopwatch::start);
+        // Carry the fittest individuals over unchanged so the best solution
+        // found so far can't be lost to crossover and mutation
+        let elite_count = self.elitism.count(population.len());
+        let mut ranked: Vec<_> = (0..population.len()).collect();
+        if elite_count > 0 {
+            ranked.sort_by(|&a, &b| population[b].fitness().total_cmp(&population[a].fitness()));
+        }
+        let elites = &ranked[..elite_count];
+
+        let children = self.breed(rng, population, population.len() - elite_count);
+        let mut parentage: Vec<_> = elites
+            .iter()
+            .map(|&elite| Parentage::Kept(elite))
+            .chain(children.iter().map(Offspring::parentage))
+            .collect();
+        let mut new_population: Vec<_> = elites
+            .iter()
+            .map(|&elite| I::create(population[elite].chromosome().clone()))
+            .chain(
+                children
+                    .into_iter()
+                    .map(|child| I::create(child.chromosome)),
+            )
+            .collect();
+        let champions = self.reinject_champions(population, &mut new_population);
+        let first_champion = parentage.len() - champions;
+        parentage[first_champion..].fill(Parentage::Champion);
+        let stats = self.complete_generation(population, &parentage, start, diversity);
+        (new_population, stats)
+    }
+
+    // Puts `population` up for the hall of fame, then has champions take the
+    // place of the last few `newcomers` bred from it; returns how many
+    pub(crate) fn reinject_champions<I>(&mut self, population: &[I], newcomers: &mut [I]) -> usize
+    where
+        I: Individual<Gene = G>,
+    {
+        match &mut self.hall_of_fame {
+            Some(hall_of_fame) => {
+                hall_of_fame.update(population, self.generation);
+                hall_of_fame.reinject(newcomers)
+            }
+            None => 0,
+        }
+    }
+
+    // Puts `migrants` in the place of the last offspring of `population`, as
+    // freshly evolved by this algorithm, so that elites and re-injected
+    // champions are kept
+    pub(crate) fn receive_migrants<I>(&mut self, population: &mut [I], migrants: &[&Chromosome<G>])
+    where
+        I: Individual<Gene = G>,
+    {
+        let champions = self
+            .hall_of_fame
+            .as_ref()
+            .map_or(0, |hall_of_fame| hall_of_fame.reinjected(population.len()));
+        let end = population.len() - champions;
+        let start = end - migrants.len();
+        assert!(
+            start >= self.elitism.count(population.len()),
+            "got more migrants than offspring to replace"
+        );
+
+        for (slot, migrant) in population[start..end].iter_mut().zip(migrants) {
+            *slot = I::create((*migrant).clone());
+        }
+        let indices: Vec<_> = (start..end).collect();
+        for observer in &mut self.observers {
+            observer.on_migrants_received(&indices);
+        }
+    }
+
+    // Wraps up a generation bred from `population`, whichever way it was
+    // bred, and returns the statistics of `population`
+    pub(crate) fn complete_generation<I>(
+        &mut self,
+        population: &[I],
+        parentage: &[Parentage],
+        start: Option<Stopwatch>,
+        diversity: Option<Diversity>,
+    ) -> Statistics<G>
+    where
+        I: Individual<Gene = G>,
+    {
+        self.generation += 1;
+        let stats = Statistics::new(population);
+        self.mutation_method.adapt(&stats);
+        for observer in &mut self.observers {
+            observer.on_population_created(parentage);
+            observer.on_generation_complete(&stats);
+        }
+        if let (Some(history), Some(start)) = (&mut self.history, start) {
+            history.record(
+                stats.clone(),
+                Some(diversity.unwrap_or_else(|| Diversity::new(population))),
+                start.elapsed(),
+            );
+        }
+        stats
+    }
+
+    // Creates `count` children, each along with where it came from
+    pub(crate) fn breed<I>(
+        &mut self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        count: usize,
+    ) -> Vec<Offspring<G>>
+    where
+        I: Individual<Gene = G>,
+    {
+        // Selection sees the transformed fitness, everything else the raw one
+        let transformed = (!self.fitness_transforms.is_empty())
+            .then(|| Transformed::population(&mut self.fitness_transforms, population));
+        let selection_method = &self.selection_method;
+        let select_many = |rng: &mut dyn RngCore, count: usize| match &transformed {
+            Some(transformed) => selection_method
+                .select_many(rng, transformed, count)
+                .into_iter()
+                .map(|transformed| transformed.individual)
+                .collect(),
+            None => selection_method.select_many(rng, population, count),
+        };
+        let crossover_method = &*self.crossover_method;
+        let mutation_method = &*self.mutation_method;
+        // Counting mutations means keeping a copy of every child around, so
+        // only bother when someone is watching
+        let observed = !self.observers.is_empty();
+        let breed = |rng: &mut dyn RngCore, parent_a: &Chromosome<G>, parent_b: &Chromosome<G>| {
+            // Create a child crossover from the two parents
+            let mut child = crossover_method.crossover(rng, parent_a, parent_b);
+            let crossed_over = observed.then(|| child.clone());
+            // Mutate the child by introducing new genes not present in the parent
+            // This helps avoid a local optimum and explore new paths in the population
+            mutation_method.mutate(rng, &mut child);
+            let mutations = crossed_over.map_or(0, |crossed_over| {
+                crossed_over
+                    .iter()
+                    .zip(child.iter())
+                    .filter(|(before, after)| !before.gene_eq(after))
+                    .count()
+            });
+            (child, mutations)
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let (parents, children): (Vec<&I>, Vec<_>) = if selection_method.selects_jointly() {
+            let parents = select_many(rng, 2 * count);
+            let children = parents
+                .chunks_exact(2)
+                .map(|parents| breed(rng, parents[0].chromosome(), parents[1].chromosome()))
+                .collect();
+            (parents, children)
+        } else {
+            let mut select: Sampler<'_, '_, I> = match &transformed {
+                Some(transformed) => {
+                    let mut sample = selection_method.sampler(transformed);
+                    Box::new(move |rng| sample(rng).individual)
+                }
+                None => selection_method.sampler(population),
+            };
+            let mut parents = Vec::with_capacity(2 * count);
+            let mut children = Vec::with_capacity(count);
+            for _ in 0..count {
                 // Select two individuals to mate
-                let parent_a = self.selection_method.select(rng, population).chromosome();
-                let parent_b = self.selection_method.select(rng, population).chromosome();
-                // Create a child crossover from the two parents
-                let mut child = self.crossover_method.crossover(rng, parent_a, parent_b);
-                // Mutate the child by introducing new genes not present in the parent
-                // This helps avoid a local optimum and explore new paths in the population
-                self.mutation_method.mutate(rng, &mut child);
-
-                I::create(child)
+                let parent_a = select(rng);
+                let parent_b = select(rng);
+                children.push(breed(rng, parent_a.chromosome(), parent_b.chromosome()));
+                parents.extend([parent_a, parent_b]);
+            }
+            (parents, children)
+        };
+
+        // Every child gets its own RNG stream seeded from the master one, so
+        // the outcome doesn't depend on how the work is spread across threads.
+        // This is what crossover and mutation methods are `Send + Sync` for.
+        #[cfg(feature = "parallel")]
+        let (parents, children): (Vec<&I>, Vec<_>) = {
+            use rand::SeedableRng;
+            use rand_chacha::ChaCha8Rng;
+            use rayon::prelude::*;
+
+            let parents = select_many(rng, 2 * count);
+            let seeds: Vec<u64> = (0..count).map(|_| rng.gen()).collect();
+            let parent_chromosomes: Vec<_> =
+                parents.iter().map(|parent| parent.chromosome()).collect();
+            let children = parent_chromosomes
+                .par_chunks_exact(2)
+                .zip(seeds)
+                .map(|(parents, seed)| {
+                    breed(&mut ChaCha8Rng::seed_from_u64(seed), parents[0], parents[1])
+                })
+                .collect();
+            (parents, children)
+        };
+
+        let indices: HashMap<_, _> = population
+            .iter()
+            .enumerate()
+            .map(|(index, individual)| (individual as *const I, index))
+            .collect();
+        let parents: Vec<_> = parents
+            .into_iter()
+            .map(|parent| indices[&(parent as *const I)])
+            .collect();
+        for observer in &mut self.observers {
+            observer.on_parents_selected(&parents);
+        }
+
+        let children: Vec<_> = children
+            .into_iter()
+            .zip(parents.chunks_exact(2))
+            .map(|((chromosome, mutations), parents)| Offspring {
+                chromosome,
+                parents: [parents[0], parents[1]],
+                mutations,
             })
             .collect();
-        let stats = Statistics::new(population);
-        (new_population, stats)
+        for observer in &mut self.observers {
+            for child in &children {
+                observer.on_child_created(&child.chromosome, child.parents);
+            }
+        }
+        children
+    }
+
+    // Keep evolving until any of the criteria fires, returning the generation
+    // that fired it together with the history of every generation that was
+    // evaluated along the way, the initial one included. Criteria are checked
+    // before breeding, so the algorithm is left at the returned generation.
+    pub fn run_until<I>(
+        &mut self,
+        rng: &mut dyn RngCore,
+        mut population: Vec<I>,
+        criteria: &mut [Box<dyn TerminationCriterion<G>>],
+    ) -> (Vec<I>, RunHistory<G>)
+    where
+        I: Individual<Gene = G>,
+    {
+        assert!(!criteria.is_empty(), "got no termination criteria");
+
+        let mut history = RunHistory::new();
+        // Time it took to breed the population, none for the initial one
+        let mut duration = Duration::ZERO;
+        loop {
+            let diversity = Diversity::new(&population);
+            history.record(
+                Statistics::new(&population),
+                Some(diversity.clone()),
+                duration,
+            );
+
+            if criteria
+                .iter_mut()
+                .any(|criterion| criterion.should_terminate(&history))
+            {
+                return (population, history);
+            }
+
+            let start = Stopwatch::start();
+            // No need to measure diversity twice when `evolve` keeps its own
+            // history
+            let diversity = self.history.is_some().then_some(diversity);
+            population = self.evolve_measured(rng, &population, diversity).0;
+            duration = start.elapsed();
+        }
+    }
+}
+
+// A freshly bred child
+pub(crate) struct Offspring<G> {
+    pub(crate) chromosome: Chromosome<G>,
+    // Indices of both parents in the population it was bred from
+    pub(crate) parents: [usize; 2],
+    // Genes changed by mutation, only counted when there are observers
+    pub(crate) mutations: usize,
+}
+
+impl<G> Offspring<G> {
+    pub(crate) fn parentage(&self) -> Parentage {
+        Parentage::Bred {
+            parents: self.parents,
+            mutations: self.mutations,
+        }
     }
 }
 
@@ -70,11 +439,13 @@ mod tests {
         TestIndividual::create(chromosome)
     }
 
+    // Breeding in parallel draws from different RNG streams
+    #[cfg(not(feature = "parallel"))]
     #[test]
     fn test() {
         let mut rng = ChaCha8Rng::from_seed(Default::default());
         // Create the algorithm
-        let ga = GeneticAlgorithm::new(
+        let mut ga = GeneticAlgorithm::new(
             RouletteWheelSelection::new(),
             UniformCrossover::new(),
             GaussianMethod::new(0.5, 0.5),
@@ -102,4 +473,793 @@ mod tests {
 
         assert_eq!(population, expected);
     }
+
+    mod elitism {
+        use super::*;
+
+        fn max_fitness(population: &[TestIndividual]) -> f32 {
+            Statistics::new(population).max_fitness()
+        }
+
+        // Harsh mutation makes children much worse than their parents more
+        // often than not
+        fn ga(elitism: Elitism) -> GeneticAlgorithm<RouletteWheelSelection> {
+            GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(1.0, 2.0),
+            )
+            .with_elitism(elitism)
+        }
+
+        fn population() -> Vec<TestIndividual> {
+            vec![
+                individual(&[1.0, 1.0, 1.0]),
+                individual(&[2.0, 2.0, 2.0]),
+                individual(&[3.0, 3.0, 3.0]),
+                individual(&[4.0, 4.0, 4.0]),
+                individual(&[5.0, 5.0, 5.0]),
+                individual(&[6.0, 6.0, 6.0]),
+            ]
+        }
+
+        #[test]
+        #[should_panic(expected = "got elitism fraction NaN")]
+        fn invalid_fraction() {
+            ga(Elitism::Fraction(f32::NAN));
+        }
+
+        #[test]
+        fn copies_elites_unchanged() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let (evolved, _) = ga(Elitism::Count(2)).evolve(&mut rng, &population());
+
+            assert_eq!(evolved.len(), 6);
+            assert_eq!(evolved[0], individual(&[6.0, 6.0, 6.0]));
+            assert_eq!(evolved[1], individual(&[5.0, 5.0, 5.0]));
+        }
+
+        #[test]
+        fn best_fitness_never_decreases() {
+            for elitism in [Elitism::Count(1), Elitism::Fraction(0.5)] {
+                let mut rng = ChaCha8Rng::from_seed(Default::default());
+                let mut ga = ga(elitism);
+                let mut population = population();
+                let mut best = max_fitness(&population);
+
+                for _ in 0..100 {
+                    (population, _) = ga.evolve(&mut rng, &population);
+                    assert!(max_fitness(&population) >= best);
+                    best = max_fitness(&population);
+                }
+            }
+        }
+
+        #[test]
+        fn best_fitness_regresses_without_elitism() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga(Elitism::Count(0));
+            let mut population = population();
+            let mut regressions = 0;
+
+            for _ in 0..100 {
+                let best = max_fitness(&population);
+                (population, _) = ga.evolve(&mut rng, &population);
+                if max_fitness(&population) < best {
+                    regressions += 1;
+                }
+            }
+
+            assert!(regressions > 0);
+        }
+    }
+
+    mod adapt {
+        use super::*;
+        use std::sync::{Arc, Mutex};
+
+        struct RecordingMutation {
+            max_fitnesses: Arc<Mutex<Vec<f32>>>,
+        }
+
+        impl MutationMethod for RecordingMutation {
+            fn mutate(&self, _rng: &mut dyn RngCore, _child: &mut Chromosome) {}
+
+            fn adapt(&mut self, statistics: &Statistics) {
+                self.max_fitnesses
+                    .lock()
+                    .unwrap()
+                    .push(statistics.max_fitness());
+            }
+        }
+
+        #[test]
+        fn mutation_method_adapts_once_per_generation() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let max_fitnesses = Arc::new(Mutex::new(Vec::new()));
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                RecordingMutation {
+                    max_fitnesses: max_fitnesses.clone(),
+                },
+            );
+            let population = vec![individual(&[1.0, 2.0]), individual(&[3.0, 4.0])];
+
+            ga.evolve(&mut rng, &population);
+            ga.evolve(&mut rng, &population);
+
+            assert_eq!(*max_fitnesses.lock().unwrap(), vec![7.0, 7.0]);
+        }
+    }
+
+    mod self_adaptive {
+        use super::*;
+
+        #[test]
+        fn evolves_strategy_parameters() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = GeneticAlgorithm::new(
+                TournamentSelection::new(2),
+                SelfAdaptiveCrossover::new(),
+                SelfAdaptiveMutation::new(0.5),
+            );
+            let mut population: Vec<_> = (0..20)
+                .map(|_| individual(&[rng.gen(), rng.gen(), rng.gen()]))
+                .collect();
+            let initial = Statistics::new(&population);
+
+            for _ in 0..30 {
+                (population, _) = ga.evolve(&mut rng, &population);
+            }
+
+            let evolved = Statistics::new(&population);
+            assert!(evolved.avg_fitness() > initial.avg_fitness() + 10.0);
+            assert!(population
+                .iter()
+                .all(|individual| individual.chromosome().has_strategy()));
+        }
+    }
+
+    mod history {
+        use super::*;
+
+        #[test]
+        fn disabled_by_default() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            );
+
+            ga.evolve(&mut rng, &[individual(&[1.0])]);
+
+            assert!(ga.history().is_none());
+        }
+
+        #[test]
+        fn records_every_generation() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            )
+            .with_history();
+            let mut population = vec![individual(&[1.0, 1.0]), individual(&[2.0, 2.0])];
+            let mut max_fitnesses = Vec::new();
+
+            for _ in 0..5 {
+                let stats;
+                (population, stats) = ga.evolve(&mut rng, &population);
+                max_fitnesses.push(stats.max_fitness());
+            }
+
+            let history = ga.history().unwrap();
+            assert_eq!(history.len(), 5);
+            for (record, max_fitness) in history.records().iter().zip(max_fitnesses) {
+                assert_eq!(record.statistics().max_fitness(), max_fitness);
+                assert!(record.diversity().is_some());
+            }
+        }
+    }
+
+    mod fitness_transform {
+        use super::*;
+
+        #[test]
+        fn selection_sees_transformed_fitness() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.0, 0.0),
+            )
+            .with_fitness_transform(Underdog);
+            let population = vec![
+                individual(&[3.0, 3.0]),
+                individual(&[1.0, 0.0]),
+                individual(&[2.0, 2.0]),
+            ];
+
+            let (evolved, stats) = ga.evolve(&mut rng, &population);
+
+            assert!(evolved
+                .iter()
+                .all(|child| *child == individual(&[1.0, 0.0])));
+            // Statistics keep reporting the raw fitness
+            assert_eq!(stats.max_fitness(), 6.0);
+        }
+
+        #[test]
+        fn sharing_keeps_minority_niche_alive() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let population: Vec<_> = (0..20)
+                .map(|i| {
+                    if i < 4 {
+                        individual(&[9.0, 0.0])
+                    } else {
+                        individual(&[10.0, 0.0])
+                    }
+                })
+                .collect();
+            let minority = |population: &[TestIndividual]| {
+                population
+                    .iter()
+                    .filter(|individual| individual.chromosome()[0] == 9.0)
+                    .count()
+            };
+
+            let evolve = |ga: &mut GeneticAlgorithm<RouletteWheelSelection>,
+                          rng: &mut ChaCha8Rng| {
+                let mut population = population.clone();
+                for _ in 0..10 {
+                    (population, _) = ga.evolve(rng, &population);
+                }
+                minority(&population)
+            };
+            let ga = || {
+                GeneticAlgorithm::new(
+                    RouletteWheelSelection::new(),
+                    // Niches only differ in the first gene, so children are
+                    // copies of either parent and both niches stay intact
+                    SinglePointCrossover::new(),
+                    GaussianMethod::new(0.0, 0.0),
+                )
+            };
+
+            let without_sharing = evolve(&mut ga(), &mut rng);
+            let with_sharing = evolve(
+                &mut ga().with_fitness_transform(FitnessSharing::new(1.0)),
+                &mut rng,
+            );
+
+            assert!(with_sharing > without_sharing);
+            assert!(with_sharing >= 5);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    mod parallel {
+        use super::*;
+
+        fn evolve(threads: usize) -> Vec<TestIndividual> {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+
+            pool.install(|| {
+                let mut rng = ChaCha8Rng::from_seed(Default::default());
+                let mut ga = GeneticAlgorithm::new(
+                    RouletteWheelSelection::new(),
+                    UniformCrossover::new(),
+                    GaussianMethod::new(0.5, 0.5),
+                );
+                let mut population: Vec<_> =
+                    (0..50).map(|i| individual(&[i as f32, 1.0, 2.0])).collect();
+                for _ in 0..10 {
+                    (population, _) = ga.evolve(&mut rng, &population);
+                }
+                population
+            })
+        }
+
+        #[test]
+        fn deterministic_regardless_of_thread_count() {
+            assert_eq!(evolve(1), evolve(4));
+        }
+    }
+
+    mod hall_of_fame {
+        use super::*;
+
+        #[test]
+        fn remembers_best_ever() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            )
+            .with_hall_of_fame(HallOfFame::new(3));
+            let mut population = vec![
+                individual(&[0.0, 0.0, 0.0]),
+                individual(&[1.0, 1.0, 1.0]),
+                individual(&[1.0, 2.0, 1.0]),
+                individual(&[2.0, 4.0, 2.0]),
+            ];
+
+            let mut best_ever = f32::NEG_INFINITY;
+            for _ in 0..10 {
+                let stats;
+                (population, stats) = ga.evolve(&mut rng, &population);
+                best_ever = best_ever.max(stats.max_fitness());
+            }
+
+            assert_eq!(ga.generation(), 10);
+            let hall_of_fame = ga.hall_of_fame().unwrap();
+            assert_eq!(hall_of_fame.len(), 3);
+            assert_eq!(hall_of_fame.best().unwrap().fitness(), best_ever);
+        }
+
+        #[test]
+        fn reinjects_champions() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            )
+            .with_hall_of_fame(HallOfFame::new(1).with_reinjection(1));
+            let population = vec![
+                individual(&[0.0, 0.0, 0.0]),
+                individual(&[2.0, 4.0, 2.0]),
+                individual(&[1.0, 1.0, 1.0]),
+            ];
+
+            let (evolved, _) = ga.evolve(&mut rng, &population);
+
+            assert_eq!(evolved.len(), 3);
+            assert_eq!(evolved[2], individual(&[2.0, 4.0, 2.0]));
+        }
+    }
+
+    mod observer {
+        use super::*;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        #[derive(Default)]
+        struct Recorder {
+            parents: Vec<usize>,
+            children: Vec<[usize; 2]>,
+            parentage: Vec<Parentage>,
+            max_fitnesses: Vec<f32>,
+        }
+
+        impl Observer for Recorder {
+            fn on_parents_selected(&mut self, parents: &[usize]) {
+                self.parents.extend_from_slice(parents);
+            }
+
+            fn on_child_created(&mut self, _child: &Chromosome, parents: [usize; 2]) {
+                self.children.push(parents);
+            }
+
+            fn on_population_created(&mut self, parentage: &[Parentage]) {
+                self.parentage = parentage.to_vec();
+            }
+
+            fn on_generation_complete(&mut self, statistics: &Statistics) {
+                self.max_fitnesses.push(statistics.max_fitness());
+            }
+        }
+
+        fn population() -> Vec<TestIndividual> {
+            (0..6).map(|n| individual(&[n as f32, 1.0, 1.0])).collect()
+        }
+
+        #[test]
+        fn notifies_observers() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let recorder = Rc::new(RefCell::new(Recorder::default()));
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            )
+            .with_elitism(Elitism::Count(1))
+            .with_hall_of_fame(HallOfFame::new(1).with_reinjection(1))
+            .with_observer(recorder.clone());
+
+            ga.evolve(&mut rng, &population());
+
+            let recorder = recorder.borrow();
+            assert_eq!(recorder.parents.len(), 10);
+            assert_eq!(
+                recorder.children,
+                recorder
+                    .parents
+                    .chunks_exact(2)
+                    .map(|parents| [parents[0], parents[1]])
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(recorder.parentage.len(), 6);
+            assert_eq!(recorder.parentage[0], Parentage::Kept(5));
+            for (parentage, &parents) in recorder.parentage[1..5].iter().zip(&recorder.children) {
+                assert!(matches!(parentage, Parentage::Bred { parents: p, .. } if *p == parents));
+            }
+            assert_eq!(recorder.parentage[5], Parentage::Champion);
+            assert_eq!(recorder.max_fitnesses, vec![7.0]);
+        }
+
+        #[test]
+        fn picks_parents_jointly() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let recorder = Rc::new(RefCell::new(Recorder::default()));
+            let mut ga = GeneticAlgorithm::new(
+                StochasticUniversalSampling::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            )
+            .with_observer(recorder.clone());
+            let population = vec![individual(&[1.0, 1.0, 1.0]); 6];
+
+            ga.evolve(&mut rng, &population);
+
+            // Everyone is equally fit, so everyone gets picked exactly twice
+            let mut parents = recorder.borrow().parents.clone();
+            parents.sort_unstable();
+            assert_eq!(parents, vec![0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5]);
+        }
+
+        #[test]
+        fn tracks_lineage() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let lineage = Rc::new(RefCell::new(Lineage::new()));
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            )
+            .with_observer(lineage.clone());
+            let mut population = population();
+
+            for _ in 0..3 {
+                (population, _) = ga.evolve(&mut rng, &population);
+            }
+
+            let lineage = lineage.borrow();
+            assert_eq!(lineage.len(), 3);
+            let ancestors = lineage.ancestors(0);
+            assert_eq!(ancestors.len(), 3);
+            assert!(ancestors
+                .iter()
+                .all(|generation| !generation.is_empty() && generation.iter().all(|&i| i < 6)));
+        }
+
+        #[test]
+        fn traces_genealogy() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let genealogy = Rc::new(RefCell::new(Genealogy::new()));
+            let mut ga = GeneticAlgorithm::new(
+                RouletteWheelSelection::new(),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            )
+            .with_elitism(Elitism::Count(1))
+            .with_observer(genealogy.clone());
+            let mut population = population();
+
+            for _ in 0..5 {
+                (population, _) = ga.evolve(&mut rng, &population);
+            }
+
+            let genealogy = genealogy.borrow();
+            assert_eq!(genealogy.generation(), 5);
+            assert_eq!(genealogy.population().len(), 6);
+            assert_eq!(genealogy.members().len(), 6 + 5 * 5);
+            // Elites go first, so that's the fittest of the previous generation
+            let best = genealogy.population()[0];
+            let generation = genealogy.member(best).generation();
+            assert!(genealogy
+                .ancestors(best)
+                .iter()
+                .all(|&ancestor| genealogy.member(ancestor).generation() < generation));
+            assert!(genealogy
+                .members()
+                .iter()
+                .any(|member| member.mutations() > 0));
+            assert!((1..=6).contains(&genealogy.surviving_lineages()));
+            assert!(genealogy.most_successful_ancestor().is_some());
+        }
+    }
+
+    mod run_until {
+        use super::*;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use std::time::Duration;
+
+        fn ga() -> GeneticAlgorithm<TournamentSelection> {
+            GeneticAlgorithm::new(
+                TournamentSelection::new(2),
+                UniformCrossover::new(),
+                GaussianMethod::new(0.5, 0.5),
+            )
+            .with_elitism(Elitism::Count(1))
+        }
+
+        fn population() -> Vec<TestIndividual> {
+            vec![
+                individual(&[0.0, 0.0, 0.0]),
+                individual(&[1.0, 1.0, 1.0]),
+                individual(&[1.0, 2.0, 1.0]),
+                individual(&[2.0, 4.0, 2.0]),
+            ]
+        }
+
+        #[test]
+        fn max_generations() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut criteria: Vec<Box<dyn TerminationCriterion>> =
+                vec![Box::new(MaxGenerations::new(7))];
+
+            let (population, history) = ga().run_until(&mut rng, population(), &mut criteria);
+
+            assert_eq!(population.len(), 4);
+            assert_eq!(history.len(), 7);
+        }
+
+        #[test]
+        fn target_fitness() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut criteria: Vec<Box<dyn TerminationCriterion>> = vec![
+                Box::new(TargetFitness::new(20.0)),
+                Box::new(MaxGenerations::new(1000)),
+            ];
+
+            let (_, history) = ga().run_until(&mut rng, population(), &mut criteria);
+
+            assert!(history.len() < 1000);
+            assert!(history.best_so_far().unwrap() >= 20.0);
+        }
+
+        #[test]
+        fn returns_the_generation_that_met_the_target() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga().with_elitism(Elitism::Count(0));
+            let mut criteria: Vec<Box<dyn TerminationCriterion>> = vec![
+                Box::new(TargetFitness::new(20.0)),
+                Box::new(MaxGenerations::new(1000)),
+            ];
+
+            let (population, history) = ga.run_until(&mut rng, population(), &mut criteria);
+
+            let stats = Statistics::new(&population);
+            assert!(stats.max_fitness() >= 20.0);
+            assert_eq!(
+                stats.max_fitness(),
+                history.last().unwrap().statistics().max_fitness()
+            );
+        }
+
+        #[test]
+        fn shares_diversity_with_own_history() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = ga().with_history();
+            let mut criteria: Vec<Box<dyn TerminationCriterion>> =
+                vec![Box::new(MaxGenerations::new(3))];
+
+            let (_, history) = ga.run_until(&mut rng, population(), &mut criteria);
+
+            // The last generation wasn't bred from
+            let own_history = ga.history().unwrap();
+            assert_eq!(own_history.len(), history.len() - 1);
+            for (record, own_record) in history.records().iter().zip(own_history.records()) {
+                assert_eq!(
+                    record.diversity().unwrap().mean_pairwise_distance(),
+                    own_record.diversity().unwrap().mean_pairwise_distance()
+                );
+            }
+        }
+
+        #[derive(Default)]
+        struct Counter {
+            populations: usize,
+            generations: usize,
+        }
+
+        impl Observer for Counter {
+            fn on_population_created(&mut self, _parentage: &[Parentage]) {
+                self.populations += 1;
+            }
+
+            fn on_generation_complete(&mut self, _statistics: &Statistics) {
+                self.generations += 1;
+            }
+        }
+
+        #[test]
+        fn stops_at_the_returned_generation() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let counter = Rc::new(RefCell::new(Counter::default()));
+            let mut ga = ga().with_observer(counter.clone());
+            let mut criteria: Vec<Box<dyn TerminationCriterion>> =
+                vec![Box::new(MaxGenerations::new(5))];
+
+            let (_, history) = ga.run_until(&mut rng, population(), &mut criteria);
+
+            // The initial population plus four bred from it
+            assert_eq!(history.len(), 5);
+            assert_eq!(ga.generation(), 4);
+            assert_eq!(counter.borrow().populations, 4);
+            assert_eq!(counter.borrow().generations, 4);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        #[test]
+        fn first_criterion_to_fire_wins() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut criteria: Vec<Box<dyn TerminationCriterion>> = vec![
+                Box::new(MaxGenerations::new(1000)),
+                Box::new(WallClockBudget::new(Duration::ZERO)),
+            ];
+
+            let (_, history) = ga().run_until(&mut rng, population(), &mut criteria);
+
+            assert_eq!(history.len(), 1);
+        }
+
+        #[test]
+        #[should_panic]
+        fn no_criteria() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            ga().run_until(&mut rng, population(), &mut []);
+        }
+    }
+
+    mod one_max {
+        use super::*;
+
+        #[derive(Clone, Debug)]
+        struct Bits(Chromosome<bool>);
+
+        impl Individual for Bits {
+            type Gene = bool;
+
+            fn create(chromosome: Chromosome<bool>) -> Self {
+                Self(chromosome)
+            }
+
+            fn chromosome(&self) -> &Chromosome<bool> {
+                &self.0
+            }
+
+            fn fitness(&self) -> f32 {
+                self.0.iter().filter(|&&bit| bit).count() as f32
+            }
+        }
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = GeneticAlgorithm::new(
+                TournamentSelection::new(3),
+                UniformCrossover::new(),
+                BitFlipMutation::new(1.0 / 32.0),
+            )
+            .with_elitism(Elitism::Count(1));
+
+            let population = (0..30)
+                .map(|_| Bits::create((0..32).map(|_| rng.gen_bool(0.5)).collect()))
+                .collect();
+
+            let mut criteria: Vec<Box<dyn TerminationCriterion<bool>>> = vec![
+                Box::new(TargetFitness::new(32.0)),
+                Box::new(MaxGenerations::new(200)),
+            ];
+
+            let (_, history) = ga.run_until(&mut rng, population, &mut criteria);
+
+            assert_eq!(history.best_so_far(), Some(32.0));
+            assert!(history.len() < 200);
+        }
+    }
+
+    mod travelling_salesman {
+        use super::*;
+        use std::f32::consts::PI;
+
+        const CITIES: usize = 10;
+
+        // Cities evenly spread on a unit circle, so the shortest tour visits
+        // them in order around it
+        fn city(index: usize) -> (f32, f32) {
+            let angle = 2.0 * PI * index as f32 / CITIES as f32;
+            (angle.cos(), angle.sin())
+        }
+
+        fn optimal_length() -> f32 {
+            CITIES as f32 * 2.0 * (PI / CITIES as f32).sin()
+        }
+
+        #[derive(Clone, Debug)]
+        struct Tour(Chromosome<usize>);
+
+        impl Tour {
+            fn length(&self) -> f32 {
+                (0..CITIES)
+                    .map(|i| {
+                        let (ax, ay) = city(self.0[i]);
+                        let (bx, by) = city(self.0[(i + 1) % CITIES]);
+                        ((ax - bx).powi(2) + (ay - by).powi(2)).sqrt()
+                    })
+                    .sum()
+            }
+        }
+
+        impl Individual for Tour {
+            type Gene = usize;
+
+            fn create(chromosome: Chromosome<usize>) -> Self {
+                Self(chromosome)
+            }
+
+            fn chromosome(&self) -> &Chromosome<usize> {
+                &self.0
+            }
+
+            fn fitness(&self) -> f32 {
+                -self.length()
+            }
+        }
+
+        fn solve(crossover_method: impl CrossoverMethod<usize> + 'static) -> RunHistory<usize> {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut ga = GeneticAlgorithm::new(
+                TournamentSelection::new(3),
+                crossover_method,
+                InversionMutation::new(0.3),
+            )
+            .with_elitism(Elitism::Count(2));
+
+            let population = (0..50)
+                .map(|_| {
+                    let mut cities: Vec<_> = (0..CITIES).collect();
+                    cities.shuffle(&mut rng);
+                    Tour::create(cities.into_iter().collect())
+                })
+                .collect();
+
+            let mut criteria: Vec<Box<dyn TerminationCriterion<usize>>> = vec![
+                Box::new(TargetFitness::new(-optimal_length() - 1e-4)),
+                Box::new(MaxGenerations::new(300)),
+            ];
+
+            let (population, history) = ga.run_until(&mut rng, population, &mut criteria);
+
+            for tour in &population {
+                let mut cities = tour.0.genes().to_vec();
+                cities.sort_unstable();
+                assert_eq!(cities, (0..CITIES).collect::<Vec<_>>());
+            }
+
+            history
+        }
+
+        #[test]
+        fn order_crossover() {
+            let history = solve(OrderCrossover::new());
+            assert!(history.len() < 300);
+        }
+
+        #[test]
+        fn partially_mapped_crossover() {
+            let history = solve(PartiallyMappedCrossover::new());
+            assert!(history.len() < 300);
+        }
+    }
 }
@@ -1,9 +1,36 @@
-pub use self::gaussian::*;
+pub use self::{
+    adaptive::*, bit_flip::*, cauchy::*, gaussian::*, integer_range::*, inversion::*, normal::*,
+    polynomial::*, self_adaptive::*, swap::*, uniform_reset::*,
+};
 
 use crate::*;
 
+mod adaptive;
+mod bit_flip;
+mod cauchy;
 mod gaussian;
+mod integer_range;
+mod inversion;
+mod normal;
+mod polynomial;
+mod self_adaptive;
+mod swap;
+mod uniform_reset;
 
-pub trait MutationMethod {
-    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome);
+pub trait MutationMethod<G = f32>: Send + Sync {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome<G>);
+
+    // Called once per generation with the statistics of the population the
+    // children were bred from, so the method can tune itself for the next one
+    fn adapt(&mut self, _statistics: &Statistics<G>) {}
+
+    // Whatever `adapt` has changed so far, so a checkpointed run can pick up
+    // where it left off; stateless methods have nothing to save
+    #[cfg(feature = "serde")]
+    fn state(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    #[cfg(feature = "serde")]
+    fn restore_state(&mut self, _state: serde_json::Value) {}
 }
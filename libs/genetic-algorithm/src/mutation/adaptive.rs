@@ -0,0 +1,262 @@
+use crate::*;
+use rand_distr::{Distribution, Normal};
+
+// Rescale the mutation magnitude by this factor, as suggested by Schwefel for
+// the 1/5th success rule
+const STEP_FACTOR: f32 = 0.85;
+
+// Normally distributed mutation which tunes itself from one generation to the
+// next based on how the population's best fitness develops:
+//
+// - 1/5th success rule: if the best fitness improved in more than a fifth of
+//   the last `window` generations, the magnitude grows to explore further;
+//   if it improved less often, the magnitude shrinks to fine-tune.
+// - Stagnation detection: after `patience` generations without any
+//   improvement the mutation chance doubles, and falls back to its initial
+//   value as soon as the best fitness improves again.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdaptiveMutation {
+    // Probability of changing a gene
+    chance: f32,
+    initial_chance: f32,
+    // Standard deviation of that change
+    coeff: f32,
+    min_coeff: f32,
+    max_coeff: f32,
+    // Number of generations the success rate is measured over
+    window: usize,
+    // Number of generations without improvement before stagnating
+    patience: usize,
+    best_fitness: Option<f32>,
+    generations: usize,
+    successes: usize,
+    stagnation: usize,
+}
+
+impl AdaptiveMutation {
+    pub fn new(chance: f32, coeff: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(coeff > 0.0);
+        Self {
+            chance,
+            initial_chance: chance,
+            coeff,
+            min_coeff: coeff / 100.0,
+            max_coeff: coeff * 10.0,
+            window: 10,
+            patience: 20,
+            best_fitness: None,
+            generations: 0,
+            successes: 0,
+            stagnation: 0,
+        }
+    }
+
+    pub fn with_window(mut self, window: usize) -> Self {
+        assert!(window > 0);
+        self.window = window;
+        self
+    }
+
+    pub fn with_patience(mut self, patience: usize) -> Self {
+        assert!(patience > 0);
+        self.patience = patience;
+        self
+    }
+
+    pub fn with_coeff_bounds(mut self, min_coeff: f32, max_coeff: f32) -> Self {
+        assert!(min_coeff > 0.0 && min_coeff <= max_coeff);
+        self.min_coeff = min_coeff;
+        self.max_coeff = max_coeff;
+        self.coeff = self.coeff.clamp(min_coeff, max_coeff);
+        self
+    }
+
+    pub fn chance(&self) -> f32 {
+        self.chance
+    }
+
+    pub fn coeff(&self) -> f32 {
+        self.coeff
+    }
+}
+
+impl MutationMethod for AdaptiveMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        let distribution = Normal::new(0.0, self.coeff).unwrap();
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene += distribution.sample(rng);
+            }
+        }
+    }
+
+    fn adapt(&mut self, statistics: &Statistics) {
+        let improved = self
+            .best_fitness
+            .is_none_or(|best| statistics.max_fitness() > best);
+
+        if improved {
+            self.best_fitness = Some(statistics.max_fitness());
+            self.successes += 1;
+            self.stagnation = 0;
+            self.chance = self.initial_chance;
+        } else {
+            self.stagnation += 1;
+            if self.stagnation >= self.patience {
+                self.stagnation = 0;
+                self.chance = (2.0 * self.chance).min(1.0);
+            }
+        }
+
+        self.generations += 1;
+        if self.generations == self.window {
+            let success_rate = self.successes as f32 / self.window as f32;
+            if success_rate > 0.2 {
+                self.coeff /= STEP_FACTOR;
+            } else if success_rate < 0.2 {
+                self.coeff *= STEP_FACTOR;
+            }
+            self.coeff = self.coeff.clamp(self.min_coeff, self.max_coeff);
+            self.generations = 0;
+            self.successes = 0;
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn state(&self) -> Option<serde_json::Value> {
+        Some(serde_json::to_value(self).expect("couldn't save the mutation state"))
+    }
+
+    #[cfg(feature = "serde")]
+    fn restore_state(&mut self, state: serde_json::Value) {
+        *self = serde_json::from_value(state).expect("got an invalid mutation state");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statistics(max_fitness: f32) -> Statistics {
+        Statistics::new(&[TestIndividual::create(
+            vec![max_fitness].into_iter().collect(),
+        )])
+    }
+
+    mod mutate {
+        use super::*;
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        #[test]
+        fn zero_chance_does_not_change_original_chromosome() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut child: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+
+            AdaptiveMutation::new(0.0, 1.0).mutate(&mut rng, &mut child);
+
+            let expected: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+            assert_eq!(child, expected);
+        }
+
+        #[test]
+        fn uses_adapted_coefficient() {
+            let mut method = AdaptiveMutation::new(1.0, 1.0).with_window(1);
+            method.adapt(&statistics(1.0));
+            method.adapt(&statistics(2.0));
+
+            let mut child: Chromosome = vec![0.0; 1000].into_iter().collect();
+            method.mutate(&mut ChaCha8Rng::from_seed(Default::default()), &mut child);
+
+            let variance = child.iter().map(|gene| gene * gene).sum::<f32>() / 1000.0;
+            approx::assert_abs_diff_eq!(variance.sqrt(), method.coeff(), epsilon = 0.05);
+        }
+    }
+
+    mod adapt {
+        use super::*;
+
+        #[test]
+        fn frequent_improvements_increase_coefficient() {
+            let mut method = AdaptiveMutation::new(0.1, 1.0).with_window(5);
+            for fitness in 1..=5 {
+                method.adapt(&statistics(fitness as f32));
+            }
+            approx::assert_relative_eq!(method.coeff(), 1.0 / STEP_FACTOR);
+        }
+
+        #[test]
+        fn rare_improvements_decrease_coefficient() {
+            let mut method = AdaptiveMutation::new(0.1, 1.0).with_window(10);
+            for _ in 0..10 {
+                method.adapt(&statistics(1.0));
+            }
+            approx::assert_relative_eq!(method.coeff(), STEP_FACTOR);
+        }
+
+        #[test]
+        fn one_in_five_improvements_keep_coefficient() {
+            let mut method = AdaptiveMutation::new(0.1, 1.0).with_window(5);
+            for _ in 0..5 {
+                method.adapt(&statistics(1.0));
+            }
+            approx::assert_relative_eq!(method.coeff(), 1.0);
+        }
+
+        #[test]
+        fn coefficient_stays_within_bounds() {
+            let mut method = AdaptiveMutation::new(0.1, 1.0)
+                .with_window(1)
+                .with_coeff_bounds(0.5, 2.0);
+            for _ in 0..100 {
+                method.adapt(&statistics(1.0));
+            }
+            approx::assert_relative_eq!(method.coeff(), 0.5);
+
+            for fitness in 2..100 {
+                method.adapt(&statistics(fitness as f32));
+            }
+            approx::assert_relative_eq!(method.coeff(), 2.0);
+        }
+
+        #[test]
+        fn stagnation_increases_chance() {
+            let mut method = AdaptiveMutation::new(0.1, 1.0).with_patience(3);
+            method.adapt(&statistics(1.0));
+
+            for _ in 0..3 {
+                approx::assert_relative_eq!(method.chance(), 0.1);
+                method.adapt(&statistics(1.0));
+            }
+            approx::assert_relative_eq!(method.chance(), 0.2);
+
+            for _ in 0..3 {
+                method.adapt(&statistics(0.5));
+            }
+            approx::assert_relative_eq!(method.chance(), 0.4);
+        }
+
+        #[test]
+        fn improvement_resets_chance() {
+            let mut method = AdaptiveMutation::new(0.1, 1.0).with_patience(1);
+            method.adapt(&statistics(1.0));
+            method.adapt(&statistics(1.0));
+            method.adapt(&statistics(1.0));
+            approx::assert_relative_eq!(method.chance(), 0.4);
+
+            method.adapt(&statistics(2.0));
+            approx::assert_relative_eq!(method.chance(), 0.1);
+        }
+
+        #[test]
+        fn chance_is_capped() {
+            let mut method = AdaptiveMutation::new(0.4, 1.0).with_patience(1);
+            for _ in 0..10 {
+                method.adapt(&statistics(1.0));
+            }
+            approx::assert_relative_eq!(method.chance(), 1.0);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::*;
+use rand_distr::{Cauchy, Distribution};
+
+// Like `NormalMutation`, but the heavy tails of the Cauchy distribution make
+// occasional big jumps much more likely, which helps escaping local optima
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CauchyMutation {
+    // Probability of changing a gene
+    chance: f32,
+    // Distribution of that change, centered on zero
+    distribution: Cauchy<f32>,
+}
+
+impl CauchyMutation {
+    pub fn new(chance: f32, scale: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        let distribution = Cauchy::new(0.0, scale).expect("got an invalid scale");
+        Self {
+            chance,
+            distribution,
+        }
+    }
+}
+
+impl MutationMethod for CauchyMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene += self.distribution.sample(rng);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, scale: f32, genes: usize) -> Vec<f32> {
+        let mut child = vec![0.0; genes].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        CauchyMutation::new(chance, scale).mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn zero_chance_does_not_change_original_chromosome() {
+        let actual = actual(0.0, 1.0, 5);
+        let expected = vec![0.0; 5];
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn half_of_the_changes_are_within_scale() {
+        let actual = actual(1.0, 0.5, 10_000);
+        let within_scale = actual.iter().filter(|gene| gene.abs() <= 0.5).count();
+        approx::assert_abs_diff_eq!(within_scale as f32 / 10_000.0, 0.5, epsilon = 0.01);
+    }
+
+    #[test]
+    fn has_heavier_tails_than_normal_distribution() {
+        let actual = actual(1.0, 0.5, 10_000);
+        // A normal distribution with the same interquartile range would put
+        // virtually nothing this far out
+        let far_out = actual.iter().filter(|gene| gene.abs() > 5.0).count();
+        assert_eq!(far_out, 611);
+    }
+}
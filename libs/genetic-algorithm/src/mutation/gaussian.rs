@@ -1,8 +1,11 @@
 use crate::*;
 use rand::RngCore;
 
+// Despite the name, genes are nudged by a uniformly distributed amount in
+// (-coeff, coeff). Kept as is so old runs can be reproduced - see
+// `NormalMutation` for normally distributed perturbations.
 #[derive(Clone, Debug)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GaussianMethod {
     // Probability of changing a gene
     chance: f32,
@@ -12,7 +15,7 @@ pub struct GaussianMethod {
 
 impl GaussianMethod {
     pub fn new(chance: f32, coeff: f32) -> Self {
-        assert!(chance >= 0.0 && chance <= 1.0);
+        assert!((0.0..=1.0).contains(&chance));
         Self { chance, coeff }
     }
 }
@@ -0,0 +1,71 @@
+use crate::*;
+use rand::distributions::uniform::SampleUniform;
+
+// Replaces integer genes with a value drawn uniformly from [min, max]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntegerRangeMutation<T> {
+    // Probability of replacing a gene
+    chance: f32,
+    min: T,
+    max: T,
+}
+
+impl<T> IntegerRangeMutation<T>
+where
+    T: Ord,
+{
+    pub fn new(chance: f32, min: T, max: T) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(min <= max);
+        Self { chance, min, max }
+    }
+}
+
+impl<T> MutationMethod<T> for IntegerRangeMutation<T>
+where
+    T: Gene + SampleUniform + Ord + Copy,
+{
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome<T>) {
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene = rng.gen_range(self.min..=self.max);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, min: i32, max: i32) -> Vec<i32> {
+        let mut child = vec![100; 1000].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        IntegerRangeMutation::new(chance, min, max).mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn zero_chance_does_not_change_original_chromosome() {
+        assert!(actual(0.0, -5, 5).iter().all(|&gene| gene == 100));
+    }
+
+    #[test]
+    fn max_chance_resets_all_genes_within_range() {
+        let actual = actual(1.0, -5, 5);
+        assert!(actual.iter().all(|gene| (-5..=5).contains(gene)));
+        // Both ends of the range are reachable
+        assert!(actual.contains(&-5));
+        assert!(actual.contains(&5));
+    }
+
+    #[test]
+    fn single_value_range() {
+        assert!(actual(1.0, 3, 3).iter().all(|&gene| gene == 3));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::*;
+
+// Reverses the order of a random run of genes, keeping permutations valid.
+// For routing problems this undoes a crossing in the route.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InversionMutation {
+    // Probability of mutating a chromosome at all
+    chance: f32,
+}
+
+impl InversionMutation {
+    pub fn new(chance: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        Self { chance }
+    }
+}
+
+impl<G> MutationMethod<G> for InversionMutation
+where
+    G: Gene,
+{
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome<G>) {
+        if child.len() < 2 || !rng.gen_bool(self.chance as _) {
+            return;
+        }
+
+        let (start, end) = segment(rng, child.len());
+        child.genes_mut()[start..end].reverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32) -> Vec<usize> {
+        let mut child = (0..10).collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        InversionMutation::new(chance).mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn zero_chance_does_not_change_original_chromosome() {
+        assert_eq!(actual(0.0), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn reverses_a_segment() {
+        assert_eq!(actual(1.0), vec![0, 1, 2, 3, 4, 5, 6, 9, 8, 7]);
+    }
+}
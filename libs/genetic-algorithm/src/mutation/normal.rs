@@ -0,0 +1,84 @@
+use crate::*;
+use rand_distr::{Distribution, Normal};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NormalMutation {
+    // Probability of changing a gene
+    chance: f32,
+    // Standard deviation of that change
+    distribution: Normal<f32>,
+}
+
+impl NormalMutation {
+    pub fn new(chance: f32, sigma: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        let distribution = Normal::new(0.0, sigma).expect("got an invalid sigma");
+        Self {
+            chance,
+            distribution,
+        }
+    }
+}
+
+impl MutationMethod for NormalMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene += self.distribution.sample(rng);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, sigma: f32, genes: usize) -> Vec<f32> {
+        let mut child = vec![0.0; genes].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        NormalMutation::new(chance, sigma).mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn zero_chance_does_not_change_original_chromosome() {
+        let actual = actual(0.0, 1.0, 5);
+        let expected = vec![0.0; 5];
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn zero_sigma_does_not_change_original_chromosome() {
+        let actual = actual(1.0, 0.0, 5);
+        let expected = vec![0.0; 5];
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn fifty_fifty_chance_changes_half_of_the_genes() {
+        let actual = actual(0.5, 1.0, 1000);
+        let changed = actual.iter().filter(|&&gene| gene != 0.0).count();
+        assert_eq!(changed, 495);
+    }
+
+    #[test]
+    fn changes_are_normally_distributed() {
+        let actual = actual(1.0, 2.0, 10_000);
+
+        let mean = actual.iter().sum::<f32>() / actual.len() as f32;
+        let variance =
+            actual.iter().map(|gene| (gene - mean).powi(2)).sum::<f32>() / actual.len() as f32;
+        // Roughly 68% of the samples fall within one standard deviation
+        let within_sigma = actual.iter().filter(|gene| gene.abs() <= 2.0).count();
+
+        approx::assert_abs_diff_eq!(mean, 0.0, epsilon = 0.05);
+        approx::assert_abs_diff_eq!(variance.sqrt(), 2.0, epsilon = 0.05);
+        approx::assert_abs_diff_eq!(within_sigma as f32 / 10_000.0, 0.6827, epsilon = 0.01);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::*;
+
+// Deb's bounded polynomial mutation: perturbations are drawn from a
+// polynomial distribution stretched over [min, max], so genes never leave the
+// bounds and small changes are much more likely than big ones. The bigger the
+// distribution index `eta`, the smaller the typical change.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolynomialMutation {
+    // Probability of changing a gene
+    chance: f32,
+    eta: f32,
+    min: f32,
+    max: f32,
+}
+
+impl PolynomialMutation {
+    pub fn new(chance: f32, eta: f32, min: f32, max: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(eta >= 0.0);
+        assert!(min < max);
+        Self {
+            chance,
+            eta,
+            min,
+            max,
+        }
+    }
+
+    fn perturb(&self, rng: &mut dyn RngCore, gene: f32) -> f32 {
+        let gene = gene.clamp(self.min, self.max);
+        let range = self.max - self.min;
+        let exponent = 1.0 / (self.eta + 1.0);
+        let u = rng.gen::<f32>();
+
+        // Shrink the distribution towards the nearest bound so it never
+        // reaches past it
+        let delta = if u < 0.5 {
+            let distance = 1.0 - (gene - self.min) / range;
+            let value = 2.0 * u + (1.0 - 2.0 * u) * distance.powf(self.eta + 1.0);
+            value.powf(exponent) - 1.0
+        } else {
+            let distance = 1.0 - (self.max - gene) / range;
+            let value = 2.0 * (1.0 - u) + 2.0 * (u - 0.5) * distance.powf(self.eta + 1.0);
+            1.0 - value.powf(exponent)
+        };
+
+        (gene + delta * range).clamp(self.min, self.max)
+    }
+}
+
+impl MutationMethod for PolynomialMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene = self.perturb(rng, *gene);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, eta: f32, genes: &[f32]) -> Vec<f32> {
+        let mut child = genes.iter().copied().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        PolynomialMutation::new(chance, eta, -1.0, 1.0).mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn zero_chance_does_not_change_original_chromosome() {
+        let actual = actual(0.0, 20.0, &[-1.0, -0.5, 0.0, 0.5, 1.0]);
+        let expected = vec![-1.0, -0.5, 0.0, 0.5, 1.0];
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn genes_stay_within_bounds() {
+        for eta in [0.0, 1.0, 20.0, 100.0] {
+            let genes: Vec<_> = (0..1000).map(|n| (n as f32) / 500.0 - 1.0).collect();
+            let actual = actual(1.0, eta, &genes);
+            assert!(actual.iter().all(|gene| (-1.0..=1.0).contains(gene)));
+        }
+    }
+
+    #[test]
+    fn genes_out_of_bounds_are_clamped() {
+        let actual = actual(1.0, 20.0, &[-5.0, 5.0]);
+        assert!(actual.iter().all(|gene| (-1.0..=1.0).contains(gene)));
+    }
+
+    #[test]
+    fn larger_eta_makes_smaller_changes() {
+        let genes = vec![0.0; 1000];
+        let mean_change = |eta| {
+            actual(1.0, eta, &genes)
+                .iter()
+                .map(|gene: &f32| gene.abs())
+                .sum::<f32>()
+                / 1000.0
+        };
+
+        assert!(mean_change(1.0) > mean_change(20.0));
+        assert!(mean_change(20.0) > mean_change(100.0));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::*;
+use rand_distr::{Distribution, StandardNormal};
+
+// Evolution strategy style mutation where every gene carries its own step
+// size in the chromosome's strategy parameters. Step sizes are mutated
+// log-normally first and then used to perturb their genes, so the population
+// evolves its own mutation rates alongside the solution.
+//
+// Chromosomes without strategy parameters (e.g. the initial, random
+// population) start out with `initial_sigma` for every gene.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelfAdaptiveMutation {
+    initial_sigma: f32,
+    // Lower bound preventing step sizes from collapsing to zero
+    min_sigma: f32,
+}
+
+impl SelfAdaptiveMutation {
+    pub fn new(initial_sigma: f32) -> Self {
+        assert!(initial_sigma > 0.0);
+        Self {
+            initial_sigma,
+            min_sigma: 1e-5,
+        }
+    }
+
+    pub fn with_min_sigma(mut self, min_sigma: f32) -> Self {
+        assert!(min_sigma > 0.0);
+        self.min_sigma = min_sigma;
+        self
+    }
+}
+
+impl MutationMethod for SelfAdaptiveMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        if child.is_empty() {
+            return;
+        }
+        if !child.has_strategy() {
+            *child = child
+                .clone()
+                .with_strategy(vec![self.initial_sigma; child.len()]);
+        }
+
+        // Learning rates recommended by Schwefel
+        let n = child.len() as f32;
+        let global_rate = 1.0 / (2.0 * n).sqrt();
+        let local_rate = 1.0 / (2.0 * n.sqrt()).sqrt();
+        let global_step = global_rate * standard_normal(rng);
+
+        for (gene, sigma) in child.iter_mut_with_strategy() {
+            let local_step = local_rate * standard_normal(rng);
+            *sigma = (*sigma * (global_step + local_step).exp()).max(self.min_sigma);
+            *gene += *sigma * standard_normal(rng);
+        }
+    }
+}
+
+fn standard_normal(rng: &mut dyn RngCore) -> f32 {
+    StandardNormal.sample(rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn chromosome(genes: usize) -> Chromosome {
+        vec![0.0; genes].into_iter().collect()
+    }
+
+    #[test]
+    fn initializes_missing_strategy() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = chromosome(100);
+
+        SelfAdaptiveMutation::new(0.1).mutate(&mut rng, &mut child);
+
+        assert_eq!(child.strategy().len(), 100);
+        // Log-normal mutation keeps step sizes in the same order of magnitude
+        assert!(child
+            .strategy()
+            .iter()
+            .all(|&sigma| sigma > 0.01 && sigma < 1.0));
+    }
+
+    #[test]
+    fn mutates_genes_according_to_their_step_size() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let strategy: Vec<_> = (0..1000)
+            .map(|n| if n % 2 == 0 { 0.01 } else { 1.0 })
+            .collect();
+        let mut child = chromosome(1000).with_strategy(strategy);
+
+        SelfAdaptiveMutation::new(0.1).mutate(&mut rng, &mut child);
+
+        let mean_change = |parity| {
+            child
+                .iter()
+                .enumerate()
+                .filter(|(n, _)| n % 2 == parity)
+                .map(|(_, gene)| gene.abs())
+                .sum::<f32>()
+                / 500.0
+        };
+        assert!(mean_change(1) > 50.0 * mean_change(0));
+    }
+
+    #[test]
+    fn step_sizes_stay_above_minimum() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = chromosome(10).with_strategy(vec![1e-4; 10]);
+        let method = SelfAdaptiveMutation::new(0.1).with_min_sigma(1e-3);
+
+        for _ in 0..100 {
+            method.mutate(&mut rng, &mut child);
+        }
+
+        assert!(child.strategy().iter().all(|&sigma| sigma >= 1e-3));
+    }
+
+    #[test]
+    fn empty_chromosome() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = chromosome(0);
+
+        SelfAdaptiveMutation::new(0.1).mutate(&mut rng, &mut child);
+
+        assert!(!child.has_strategy());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::*;
+
+// Swaps two random genes, keeping permutations valid
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapMutation {
+    // Probability of mutating a chromosome at all
+    chance: f32,
+}
+
+impl SwapMutation {
+    pub fn new(chance: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        Self { chance }
+    }
+}
+
+impl<G> MutationMethod<G> for SwapMutation
+where
+    G: Gene,
+{
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome<G>) {
+        if child.len() < 2 || !rng.gen_bool(self.chance as _) {
+            return;
+        }
+
+        let a = rng.gen_range(0..child.len());
+        // Pick a different position by skipping over the first one
+        let b = (a + rng.gen_range(1..child.len())) % child.len();
+        child.genes_mut().swap(a, b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32) -> Vec<usize> {
+        let mut child = (0..10).collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        SwapMutation::new(chance).mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn zero_chance_does_not_change_original_chromosome() {
+        assert_eq!(actual(0.0), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn swaps_exactly_two_genes() {
+        let actual = actual(1.0);
+
+        let moved = actual
+            .iter()
+            .enumerate()
+            .filter(|(i, &gene)| *i != gene)
+            .count();
+        assert_eq!(moved, 2);
+
+        let mut sorted = actual.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn single_gene() {
+        let mut child: Chromosome<usize> = vec![0].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        SwapMutation::new(1.0).mutate(&mut rng, &mut child);
+
+        assert_eq!(child.genes(), &[0]);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::*;
+
+// Replaces genes with a value drawn uniformly from [min, max), ignoring what
+// the gene was before
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UniformResetMutation {
+    // Probability of replacing a gene
+    chance: f32,
+    min: f32,
+    max: f32,
+}
+
+impl UniformResetMutation {
+    pub fn new(chance: f32, min: f32, max: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(min < max);
+        Self { chance, min, max }
+    }
+}
+
+impl MutationMethod for UniformResetMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene = rng.gen_range(self.min..self.max);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32) -> Vec<f32> {
+        let mut child = vec![10.0; 1000].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        UniformResetMutation::new(chance, -1.0, 1.0).mutate(&mut rng, &mut child);
+
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn zero_chance_does_not_change_original_chromosome() {
+        assert!(actual(0.0).iter().all(|&gene| gene == 10.0));
+    }
+
+    #[test]
+    fn fifty_fifty_chance_resets_half_of_the_genes() {
+        let reset = actual(0.5).iter().filter(|&&gene| gene != 10.0).count();
+        assert_eq!(reset, 492);
+    }
+
+    #[test]
+    fn max_chance_resets_all_genes_within_range() {
+        assert!(actual(1.0).iter().all(|gene| (-1.0..1.0).contains(gene)));
+    }
+}
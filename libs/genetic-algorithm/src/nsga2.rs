@@ -0,0 +1,202 @@
+use crate::*;
+
+// NSGA-II (Deb et al., 2002) for individuals with several objectives.
+//
+// Individuals are usually evaluated outside of the algorithm (e.g. by letting
+// animals live through a generation), so instead of evaluating offspring
+// right away, `evolve` keeps the survivors of the previous call around and
+// merges them with the freshly evaluated population: the best half of both
+// (by Pareto rank, then crowding distance) survives and breeds the next
+// generation via binary tournaments.
+pub struct Nsga2<G = f32> {
+    crossover_method: Box<dyn CrossoverMethod<G>>,
+    mutation_method: Box<dyn MutationMethod<G>>,
+    survivors: Vec<Chromosome<G>>,
+    survivor_objectives: Vec<Vec<f32>>,
+}
+
+impl<G> Nsga2<G>
+where
+    G: Gene,
+{
+    pub fn new(
+        crossover_method: impl CrossoverMethod<G> + 'static,
+        mutation_method: impl MutationMethod<G> + 'static,
+    ) -> Self {
+        Self {
+            crossover_method: Box::new(crossover_method),
+            mutation_method: Box::new(mutation_method),
+            survivors: Vec::new(),
+            survivor_objectives: Vec::new(),
+        }
+    }
+
+    // Chromosomes which made it through the last environmental selection
+    pub fn survivors(&self) -> &[Chromosome<G>] {
+        &self.survivors
+    }
+
+    // The survivors keep the objectives they were evaluated with in their own
+    // generation, so objectives have to depend on the chromosome alone - with
+    // a changing environment, old survivors would compete on stale values.
+    pub fn evolve<I>(&mut self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics<G>)
+    where
+        I: MultiObjectiveIndividual<Gene = G>,
+    {
+        assert!(!population.is_empty());
+
+        let stats = Statistics::new_multi_objective(population);
+
+        let mut chromosomes = std::mem::take(&mut self.survivors);
+        let mut objectives = std::mem::take(&mut self.survivor_objectives);
+        for individual in population {
+            chromosomes.push(individual.chromosome().clone());
+            objectives.push(individual.objectives());
+        }
+
+        // Environmental selection: fill up with whole fronts, then break the
+        // tie on the front which doesn't fit by preferring isolated solutions
+        let mut rank = vec![0; chromosomes.len()];
+        let mut crowding = vec![0.0; chromosomes.len()];
+        let mut survivors = Vec::with_capacity(population.len());
+
+        for (front_rank, front) in non_dominated_sort(&objectives).into_iter().enumerate() {
+            if survivors.len() >= population.len() {
+                break;
+            }
+
+            let distances = crowding_distance(&objectives, &front);
+            let mut front: Vec<_> = front.into_iter().zip(distances).collect();
+            for &(index, distance) in &front {
+                rank[index] = front_rank;
+                crowding[index] = distance;
+            }
+
+            front.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+            front.truncate(population.len() - survivors.len());
+            survivors.extend(front.into_iter().map(|(index, _)| index));
+        }
+
+        // Mating selection: binary tournaments by the crowded-comparison
+        // operator, i.e. lower rank wins and crowding distance breaks ties
+        let tournament = |rng: &mut dyn RngCore| {
+            let a = survivors[rng.gen_range(0..survivors.len())];
+            let b = survivors[rng.gen_range(0..survivors.len())];
+            if (rank[a], -crowding[a]) <= (rank[b], -crowding[b]) {
+                a
+            } else {
+                b
+            }
+        };
+
+        let offspring = (0..population.len())
+            .map(|_| {
+                let parent_a = &chromosomes[tournament(rng)];
+                let parent_b = &chromosomes[tournament(rng)];
+                let mut child = self.crossover_method.crossover(rng, parent_a, parent_b);
+                self.mutation_method.mutate(rng, &mut child);
+                I::create(child)
+            })
+            .collect();
+
+        self.mutation_method.adapt(&stats);
+
+        self.survivors = survivors.iter().map(|&i| chromosomes[i].clone()).collect();
+        self.survivor_objectives = survivors
+            .iter()
+            .map(|&i| std::mem::take(&mut objectives[i]))
+            .collect();
+
+        (offspring, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    // Schaffer's first problem, negated for maximization: the Pareto-optimal
+    // solutions are every x within [0, 2]
+    #[derive(Clone, Debug)]
+    struct Schaffer(Chromosome);
+
+    impl Individual for Schaffer {
+        type Gene = f32;
+
+        fn fitness(&self) -> f32 {
+            self.objectives().iter().sum()
+        }
+        fn chromosome(&self) -> &Chromosome {
+            &self.0
+        }
+        fn create(chromosome: Chromosome) -> Self {
+            Self(chromosome)
+        }
+    }
+
+    impl MultiObjectiveIndividual for Schaffer {
+        fn objectives(&self) -> Vec<f32> {
+            let x = self.0[0];
+            vec![-x.powi(2), -(x - 2.0).powi(2)]
+        }
+    }
+
+    fn run(generations: usize) -> (Nsga2, Statistics) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut nsga2 = Nsga2::new(BlendCrossover::default(), NormalMutation::new(0.5, 0.2));
+        let mut population: Vec<_> = (0..40)
+            .map(|_| Schaffer::create(vec![rng.gen_range(-10.0..10.0)].into_iter().collect()))
+            .collect();
+
+        let mut stats = None;
+        for _ in 0..generations {
+            let (evolved, generation_stats) = nsga2.evolve(&mut rng, &population);
+            population = evolved;
+            stats = Some(generation_stats);
+        }
+
+        (nsga2, stats.unwrap())
+    }
+
+    #[test]
+    fn converges_to_pareto_set() {
+        let (nsga2, _) = run(50);
+
+        let xs: Vec<_> = nsga2.survivors().iter().map(|c| c[0]).collect();
+        assert_eq!(xs.len(), 40);
+        assert!(xs.iter().all(|x| (-0.05..=2.05).contains(x)), "{:?}", xs);
+    }
+
+    #[test]
+    fn spreads_along_pareto_front() {
+        let (nsga2, _) = run(50);
+
+        let xs: Vec<_> = nsga2.survivors().iter().map(|c| c[0]).collect();
+        let min = xs.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = xs.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        assert!(min < 0.2 && max > 1.8, "{}..{}", min, max);
+    }
+
+    #[test]
+    fn statistics_contain_pareto_front() {
+        let (_, stats) = run(1);
+        assert!(!stats.pareto_front().unwrap().is_empty());
+    }
+
+    #[test]
+    fn keeps_population_size() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut nsga2 = Nsga2::new(BlendCrossover::default(), NormalMutation::new(0.5, 0.2));
+        let population: Vec<_> = (0..7)
+            .map(|x| Schaffer::create(vec![x as f32].into_iter().collect()))
+            .collect();
+
+        let (offspring, _) = nsga2.evolve(&mut rng, &population);
+        let (offspring, _) = nsga2.evolve(&mut rng, &offspring);
+
+        assert_eq!(offspring.len(), 7);
+        assert_eq!(nsga2.survivors().len(), 7);
+    }
+}
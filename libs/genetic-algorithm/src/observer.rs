@@ -0,0 +1,73 @@
+pub use self::{genealogy::*, lineage::*};
+
+use crate::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod genealogy;
+mod lineage;
+
+// Gets told what's going on while a `GeneticAlgorithm` evolves, e.g. to log
+// progress or draw it; stopping early is up to a `TerminationCriterion`.
+// Indices point into the population the new generation is bred from.
+pub trait Observer<G = f32> {
+    // Every parent picked for the generation, two per child
+    fn on_parents_selected(&mut self, _parents: &[usize]) {}
+
+    // Called for every child right after it's been crossed over and mutated
+    fn on_child_created(&mut self, _child: &Chromosome<G>, _parents: [usize; 2]) {}
+
+    // Where each member of the new population came from, in order
+    fn on_population_created(&mut self, _parentage: &[Parentage]) {}
+
+    // Statistics of the population the new generation was bred from
+    fn on_generation_complete(&mut self, _statistics: &Statistics<G>) {}
+
+    // Members of the new population which were then replaced by migrants
+    // from another island, see `IslandModel`
+    fn on_migrants_received(&mut self, _indices: &[usize]) {}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Parentage {
+    // Crossed over from two parents, then mutated; `mutations` is the
+    // number of genes mutation changed
+    Bred {
+        parents: [usize; 2],
+        mutations: usize,
+    },
+    // Carried over unchanged, as an elite or because it wasn't replaced
+    Kept(usize),
+    // Re-injected from the hall of fame
+    Champion,
+    // Sent over from another island
+    Migrant,
+}
+
+// Lets the caller keep a handle on an observer handed over to the algorithm
+// and look at what it gathered
+impl<O, G> Observer<G> for Rc<RefCell<O>>
+where
+    O: Observer<G>,
+{
+    fn on_parents_selected(&mut self, parents: &[usize]) {
+        self.borrow_mut().on_parents_selected(parents);
+    }
+
+    fn on_child_created(&mut self, child: &Chromosome<G>, parents: [usize; 2]) {
+        self.borrow_mut().on_child_created(child, parents);
+    }
+
+    fn on_population_created(&mut self, parentage: &[Parentage]) {
+        self.borrow_mut().on_population_created(parentage);
+    }
+
+    fn on_generation_complete(&mut self, statistics: &Statistics<G>) {
+        self.borrow_mut().on_generation_complete(statistics);
+    }
+
+    fn on_migrants_received(&mut self, indices: &[usize]) {
+        self.borrow_mut().on_migrants_received(indices);
+    }
+}
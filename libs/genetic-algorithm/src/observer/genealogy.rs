@@ -0,0 +1,335 @@
+use crate::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+// Gives every individual a unique ID and remembers who its parents were, so
+// that the family tree of e.g. the fittest individual can be traced back.
+// Founders - the first population the algorithm saw - are numbered from 0 in
+// population order.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Genealogy {
+    // Indexed by ID
+    members: Vec<Member>,
+    // IDs of the newest population, in order
+    population: Vec<usize>,
+    generation: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Member {
+    id: usize,
+    // Empty for founders, champions re-injected from the hall of fame and
+    // migrants from other islands, one ID for children of a parent mated
+    // with itself
+    parents: Vec<usize>,
+    generation: usize,
+    // Genes changed by mutation when it was bred
+    mutations: usize,
+}
+
+impl Member {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+    pub fn parents(&self) -> &[usize] {
+        &self.parents
+    }
+    // Generation it was born in, 0 for founders
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+    pub fn mutations(&self) -> usize {
+        self.mutations
+    }
+}
+
+impl Genealogy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Everyone ever born, by ID
+    pub fn members(&self) -> &[Member] {
+        &self.members
+    }
+
+    pub fn member(&self, id: usize) -> &Member {
+        &self.members[id]
+    }
+
+    // IDs of the newest population, in the order of its individuals; e.g.
+    // `population()[statistics.best_index()]` of the population the
+    // statistics were taken from
+    pub fn population(&self) -> &[usize] {
+        &self.population
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // IDs of everyone `id` descends from, not including itself, most recent
+    // first
+    pub fn ancestors(&self, id: usize) -> Vec<usize> {
+        let mut ancestors = BTreeSet::new();
+        let mut pending = self.members[id].parents.clone();
+
+        while let Some(ancestor) = pending.pop() {
+            if ancestors.insert(ancestor) {
+                pending.extend_from_slice(&self.members[ancestor].parents);
+            }
+        }
+
+        ancestors.into_iter().rev().collect()
+    }
+
+    // Member with the most descendants in the newest population, ties going
+    // to the older one; `None` until a population has been bred
+    pub fn most_successful_ancestor(&self) -> Option<usize> {
+        let mut descendants = BTreeMap::<usize, usize>::new();
+        for id in self.living() {
+            for ancestor in self.ancestors(id) {
+                *descendants.entry(ancestor).or_default() += 1;
+            }
+        }
+
+        descendants
+            .into_iter()
+            .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+            .map(|(ancestor, _)| ancestor)
+    }
+
+    // Number of founders (and re-injected champions and migrants, which start
+    // over with no known parents) still represented in the newest population
+    pub fn surviving_lineages(&self) -> usize {
+        let roots: BTreeSet<_> = self
+            .living()
+            .flat_map(|id| self.ancestors(id).into_iter().chain([id]))
+            .filter(|&id| self.members[id].parents.is_empty())
+            .collect();
+        roots.len()
+    }
+
+    // Graphviz graph with an arrow from every parent to its children and the
+    // newest population filled in
+    pub fn to_dot(&self) -> String {
+        let living: BTreeSet<_> = self.living().collect();
+        let mut dot = String::from("digraph genealogy {\n");
+
+        for member in &self.members {
+            write!(
+                dot,
+                "    {} [label=\"#{}\\ngeneration {}\\n{} mutations\"",
+                member.id, member.id, member.generation, member.mutations
+            )
+            .unwrap();
+            if living.contains(&member.id) {
+                dot.push_str(", style=filled");
+            }
+            dot.push_str("];\n");
+        }
+        for member in &self.members {
+            for parent in &member.parents {
+                writeln!(dot, "    {} -> {};", parent, member.id).unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    // Array with one object per member, by ID, plus whether it's part of
+    // the newest population
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        #[derive(serde::Serialize)]
+        struct JsonMember<'a> {
+            #[serde(flatten)]
+            member: &'a Member,
+            alive: bool,
+        }
+
+        let living: BTreeSet<_> = self.living().collect();
+        let members: Vec<_> = self
+            .members
+            .iter()
+            .map(|member| JsonMember {
+                member,
+                alive: living.contains(&member.id),
+            })
+            .collect();
+        serde_json::to_string(&members).expect("couldn't serialize the genealogy")
+    }
+
+    fn living(&self) -> impl Iterator<Item = usize> + '_ {
+        self.population.iter().copied()
+    }
+
+    fn birth(&mut self, parents: Vec<usize>, mutations: usize) -> usize {
+        let id = self.members.len();
+        self.members.push(Member {
+            id,
+            parents,
+            generation: self.generation,
+            mutations,
+        });
+        id
+    }
+}
+
+impl<G> Observer<G> for Genealogy {
+    fn on_population_created(&mut self, parentage: &[Parentage]) {
+        // Populations keep their size from one generation to the next, so
+        // that's how many founders there were
+        if self.members.is_empty() {
+            self.population = (0..parentage.len())
+                .map(|_| self.birth(Vec::new(), 0))
+                .collect();
+        }
+
+        self.generation += 1;
+        let previous = std::mem::take(&mut self.population);
+        self.population = parentage
+            .iter()
+            .map(|parentage| match *parentage {
+                Parentage::Bred {
+                    parents: [a, b],
+                    mutations,
+                } => {
+                    let mut parents = vec![previous[a], previous[b]];
+                    parents.dedup();
+                    self.birth(parents, mutations)
+                }
+                Parentage::Kept(index) => previous[index],
+                Parentage::Champion | Parentage::Migrant => self.birth(Vec::new(), 0),
+            })
+            .collect();
+    }
+
+    // Whoever got replaced never made it into the population
+    fn on_migrants_received(&mut self, indices: &[usize]) {
+        for &index in indices {
+            self.population[index] = self.birth(Vec::new(), 0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bred(a: usize, b: usize, mutations: usize) -> Parentage {
+        Parentage::Bred {
+            parents: [a, b],
+            mutations,
+        }
+    }
+
+    // Founders 0, 1 and 2, then:
+    //   generation 1: 0 kept, 3 = 0 x 1, 4 = 1 x 1
+    //   generation 2: 0 kept, 5 = 3 x 0, 6 champion
+    fn genealogy() -> Genealogy {
+        let mut genealogy = Genealogy::new();
+        Observer::<f32>::on_population_created(
+            &mut genealogy,
+            &[Parentage::Kept(0), bred(0, 1, 2), bred(1, 1, 0)],
+        );
+        Observer::<f32>::on_population_created(
+            &mut genealogy,
+            &[Parentage::Kept(0), bred(1, 0, 1), Parentage::Champion],
+        );
+        genealogy
+    }
+
+    #[test]
+    fn assigns_ids() {
+        let genealogy = genealogy();
+
+        assert_eq!(genealogy.generation(), 2);
+        assert_eq!(genealogy.population(), &[0, 5, 6]);
+        assert_eq!(genealogy.members().len(), 7);
+
+        let member = genealogy.member(3);
+        assert_eq!(member.parents(), &[0, 1]);
+        assert_eq!(member.generation(), 1);
+        assert_eq!(member.mutations(), 2);
+        assert_eq!(genealogy.member(4).parents(), &[1]);
+        assert_eq!(genealogy.member(5).parents(), &[3, 0]);
+        assert!(genealogy.member(6).parents().is_empty());
+    }
+
+    #[test]
+    fn ancestors() {
+        let genealogy = genealogy();
+
+        assert_eq!(genealogy.ancestors(5), vec![3, 1, 0]);
+        assert_eq!(genealogy.ancestors(0), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn most_successful_ancestor() {
+        assert_eq!(Genealogy::new().most_successful_ancestor(), None);
+        // 0 is an ancestor of 5 only, just like 1 and 3, but the oldest
+        assert_eq!(genealogy().most_successful_ancestor(), Some(0));
+    }
+
+    #[test]
+    fn surviving_lineages() {
+        // 0 and 1 through 0 and 5, and the champion; 2 died out
+        assert_eq!(genealogy().surviving_lineages(), 3);
+    }
+
+    #[test]
+    fn migrants() {
+        let mut genealogy = genealogy();
+        Observer::<f32>::on_migrants_received(&mut genealogy, &[1]);
+
+        assert_eq!(genealogy.population(), &[0, 7, 6]);
+        assert!(genealogy.member(7).parents().is_empty());
+        assert_eq!(genealogy.member(7).generation(), 2);
+        assert!(genealogy.ancestors(7).is_empty());
+    }
+
+    #[test]
+    fn to_dot() {
+        let dot = genealogy().to_dot();
+
+        assert!(dot.starts_with("digraph genealogy {\n"));
+        assert!(dot.contains("    5 [label=\"#5\\ngeneration 2\\n1 mutations\", style=filled];\n"));
+        assert!(dot.contains("    4 [label=\"#4\\ngeneration 1\\n0 mutations\"];\n"));
+        assert!(dot.contains("    3 -> 5;\n"));
+        assert!(dot.contains("    0 -> 5;\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn to_json() {
+        let json: serde_json::Value = serde_json::from_str(&genealogy().to_json()).unwrap();
+
+        assert_eq!(json.as_array().unwrap().len(), 7);
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "id": 0,
+                "parents": [],
+                "generation": 0,
+                "mutations": 0,
+                "alive": true
+            })
+        );
+        assert_eq!(
+            json[5],
+            serde_json::json!({
+                "id": 5,
+                "parents": [3, 0],
+                "generation": 2,
+                "mutations": 1,
+                "alive": true
+            })
+        );
+        assert_eq!(json[4]["alive"], false);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::*;
+use std::collections::BTreeSet;
+
+// Remembers where every member of every generation came from, so that the
+// ancestry of the newest individuals can be traced back
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lineage {
+    // Oldest generation first
+    generations: Vec<Vec<Parentage>>,
+}
+
+impl Lineage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn generations(&self) -> &[Vec<Parentage>] {
+        &self.generations
+    }
+
+    pub fn len(&self) -> usize {
+        self.generations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.generations.is_empty()
+    }
+
+    // Indices of the ancestors of member `index` of the newest population,
+    // one set per earlier generation, newest first. Champions re-injected
+    // from the hall of fame and migrants have no known ancestors.
+    pub fn ancestors(&self, index: usize) -> Vec<BTreeSet<usize>> {
+        let mut members = BTreeSet::from([index]);
+
+        self.generations
+            .iter()
+            .rev()
+            .map(|parentage| {
+                members = members
+                    .iter()
+                    .flat_map(|&member| match parentage[member] {
+                        Parentage::Bred { parents, .. } => parents.to_vec(),
+                        Parentage::Kept(parent) => vec![parent],
+                        Parentage::Champion | Parentage::Migrant => Vec::new(),
+                    })
+                    .collect();
+                members.clone()
+            })
+            .collect()
+    }
+}
+
+impl<G> Observer<G> for Lineage {
+    fn on_population_created(&mut self, parentage: &[Parentage]) {
+        self.generations.push(parentage.to_vec());
+    }
+
+    fn on_migrants_received(&mut self, indices: &[usize]) {
+        let newest = self
+            .generations
+            .last_mut()
+            .expect("got migrants before any population");
+        for &index in indices {
+            newest[index] = Parentage::Migrant;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bred(a: usize, b: usize) -> Parentage {
+        Parentage::Bred {
+            parents: [a, b],
+            mutations: 0,
+        }
+    }
+
+    #[test]
+    fn ancestors() {
+        let mut lineage = Lineage::new();
+        Observer::<f32>::on_population_created(
+            &mut lineage,
+            &[Parentage::Kept(2), bred(0, 1), bred(1, 1)],
+        );
+        Observer::<f32>::on_population_created(
+            &mut lineage,
+            &[Parentage::Kept(0), bred(0, 1), Parentage::Champion],
+        );
+
+        assert_eq!(lineage.len(), 2);
+        assert_eq!(
+            lineage.ancestors(1),
+            vec![BTreeSet::from([0, 1]), BTreeSet::from([0, 1, 2])]
+        );
+        assert_eq!(
+            lineage.ancestors(0),
+            vec![BTreeSet::from([0]), BTreeSet::from([2])]
+        );
+        assert_eq!(lineage.ancestors(2), vec![BTreeSet::new(), BTreeSet::new()]);
+    }
+
+    #[test]
+    fn migrants() {
+        let mut lineage = Lineage::new();
+        Observer::<f32>::on_population_created(&mut lineage, &[bred(0, 1), bred(1, 1)]);
+        Observer::<f32>::on_migrants_received(&mut lineage, &[1]);
+
+        assert_eq!(lineage.generations()[0][1], Parentage::Migrant);
+        assert_eq!(lineage.ancestors(0), vec![BTreeSet::from([0, 1])]);
+        assert_eq!(lineage.ancestors(1), vec![BTreeSet::new()]);
+    }
+}
@@ -0,0 +1,218 @@
+// Building blocks for multi-objective optimization. Every objective is
+// maximized, just like `Individual::fitness`.
+
+// Whether `a` is at least as good as `b` in every objective and strictly
+// better in at least one
+pub fn dominates(a: &[f32], b: &[f32]) -> bool {
+    assert_eq!(a.len(), b.len());
+
+    let mut strictly_better = false;
+    for (a, b) in a.iter().zip(b) {
+        if a < b {
+            return false;
+        }
+        if a > b {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+// Splits the population into fronts of mutually non-dominated individuals;
+// the first front is the Pareto front, the second one is what remains after
+// removing it and so on. Returns indices into `objectives`.
+//
+// Deb's fast non-dominated sort, O(objectives * n²).
+pub fn non_dominated_sort(objectives: &[Vec<f32>]) -> Vec<Vec<usize>> {
+    // For each individual: who it dominates and by how many it's dominated
+    let mut dominated = vec![Vec::new(); objectives.len()];
+    let mut domination_count = vec![0; objectives.len()];
+
+    for a in 0..objectives.len() {
+        for b in (a + 1)..objectives.len() {
+            if dominates(&objectives[a], &objectives[b]) {
+                dominated[a].push(b);
+                domination_count[b] += 1;
+            } else if dominates(&objectives[b], &objectives[a]) {
+                dominated[b].push(a);
+                domination_count[a] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+    let mut front: Vec<_> = (0..objectives.len())
+        .filter(|&i| domination_count[i] == 0)
+        .collect();
+
+    while !front.is_empty() {
+        let mut next = Vec::new();
+        for &a in &front {
+            for &b in &dominated[a] {
+                domination_count[b] -= 1;
+                if domination_count[b] == 0 {
+                    next.push(b);
+                }
+            }
+        }
+        fronts.push(front);
+        front = next;
+    }
+
+    fronts
+}
+
+// Indices of the individuals not dominated by anyone
+pub fn pareto_front(objectives: &[Vec<f32>]) -> Vec<usize> {
+    non_dominated_sort(objectives)
+        .into_iter()
+        .next()
+        .unwrap_or_default()
+}
+
+// How isolated each member of `front` is from its neighbours, summed over
+// every objective and normalized by that objective's range; the extremes get
+// an infinite distance so they're always preferred. Objectives on which the
+// whole front is equal are skipped, as they have no extremes. Returned in the
+// same order as `front`.
+pub fn crowding_distance(objectives: &[Vec<f32>], front: &[usize]) -> Vec<f32> {
+    let mut distances = vec![0.0; front.len()];
+    if front.len() <= 2 {
+        distances.fill(f32::INFINITY);
+        return distances;
+    }
+
+    // Values of a single objective across the front, one objective at a time
+    let columns = (0..objectives[front[0]].len())
+        .map(|objective| -> Vec<_> { front.iter().map(|&i| objectives[i][objective]).collect() });
+    let mut order: Vec<_> = (0..front.len()).collect();
+
+    for values in columns {
+        order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+        let first = order[0];
+        let last = order[order.len() - 1];
+        let range = values[last] - values[first];
+        if range <= 0.0 {
+            continue;
+        }
+
+        distances[first] = f32::INFINITY;
+        distances[last] = f32::INFINITY;
+
+        for window in order.windows(3) {
+            distances[window[1]] += (values[window[2]] - values[window[0]]) / range;
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod dominates {
+        use super::*;
+
+        #[test]
+        fn better_in_every_objective() {
+            assert!(dominates(&[2.0, 2.0], &[1.0, 1.0]));
+            assert!(!dominates(&[1.0, 1.0], &[2.0, 2.0]));
+        }
+
+        #[test]
+        fn better_in_one_objective() {
+            assert!(dominates(&[2.0, 1.0], &[1.0, 1.0]));
+        }
+
+        #[test]
+        fn trade_off() {
+            assert!(!dominates(&[2.0, 1.0], &[1.0, 2.0]));
+            assert!(!dominates(&[1.0, 2.0], &[2.0, 1.0]));
+        }
+
+        #[test]
+        fn equal() {
+            assert!(!dominates(&[1.0, 1.0], &[1.0, 1.0]));
+        }
+    }
+
+    mod non_dominated_sort {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let objectives = vec![
+                vec![1.0, 1.0], // 3rd front
+                vec![3.0, 1.0], // Pareto front
+                vec![0.0, 0.0], // 4th front
+                vec![1.0, 3.0], // Pareto front
+                vec![2.0, 2.0], // Pareto front
+                vec![1.0, 2.0], // 2nd front
+            ];
+
+            let fronts: Vec<_> = non_dominated_sort(&objectives)
+                .into_iter()
+                .map(|mut front| {
+                    front.sort_unstable();
+                    front
+                })
+                .collect();
+
+            assert_eq!(fronts, vec![vec![1, 3, 4], vec![5], vec![0], vec![2]]);
+            assert_eq!(pareto_front(&objectives).len(), 3);
+        }
+
+        #[test]
+        fn empty() {
+            assert!(non_dominated_sort(&[]).is_empty());
+            assert!(pareto_front(&[]).is_empty());
+        }
+    }
+
+    mod crowding_distance {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let objectives = vec![
+                vec![0.0, 4.0],
+                vec![3.0, 1.0],
+                vec![1.0, 3.0],
+                vec![4.0, 0.0],
+            ];
+
+            let actual = crowding_distance(&objectives, &[0, 1, 2, 3]);
+
+            assert_eq!(actual[0], f32::INFINITY);
+            assert_eq!(actual[3], f32::INFINITY);
+            // (3 - 0) / 4 for both objectives
+            approx::assert_relative_eq!(actual[2], 1.5);
+            // (4 - 1) / 4 for both objectives
+            approx::assert_relative_eq!(actual[1], 1.5);
+        }
+
+        #[test]
+        fn small_front() {
+            let objectives = vec![vec![0.0], vec![1.0]];
+            assert_eq!(
+                crowding_distance(&objectives, &[0, 1]),
+                vec![f32::INFINITY; 2]
+            );
+        }
+
+        #[test]
+        fn flat_objective_is_ignored() {
+            let objectives = vec![vec![0.0, 5.0], vec![1.0, 5.0], vec![3.0, 5.0]];
+            let actual = crowding_distance(&objectives, &[0, 1, 2]);
+            approx::assert_relative_eq!(actual[1], 1.0);
+        }
+
+        #[test]
+        fn flat_front() {
+            let objectives = vec![vec![2.0, 5.0]; 3];
+            assert_eq!(crowding_distance(&objectives, &[0, 1, 2]), vec![0.0; 3]);
+        }
+    }
+}
@@ -1,11 +1,49 @@
-pub use self::roulette_wheel::*;
+pub use self::{any::*, rank::*, roulette_wheel::*, stochastic_universal::*, tournament::*};
 
 use crate::*;
 
+mod any;
+mod rank;
 mod roulette_wheel;
+mod stochastic_universal;
+mod tournament;
+
+// Picks one individual per call, see `SelectionMethod::sampler`
+pub type Sampler<'s, 'a, I> = Box<dyn FnMut(&mut dyn RngCore) -> &'a I + 's>;
 
 pub trait SelectionMethod {
     fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
     where
         I: Individual;
+
+    // Select `count` individuals at once. Methods that need to look at the
+    // population as a whole (e.g. to sort or spread pointers) override this
+    // instead of redoing that work for every single parent.
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        (0..count).map(|_| self.select(rng, population)).collect()
+    }
+
+    // Picks individuals one at a time from the same population, e.g. two
+    // parents as each child of a generation gets bred. Methods that need to
+    // look at the population as a whole override this to do that only once.
+    fn sampler<'s, 'a: 's, I>(&'s self, population: &'a [I]) -> Sampler<'s, 'a, I>
+    where
+        I: Individual,
+    {
+        Box::new(move |rng| self.select(rng, population))
+    }
+
+    // Whether all parents of a generation have to be picked together with
+    // `select_many`, rather than two at a time as each child gets bred
+    fn selects_jointly(&self) -> bool {
+        false
+    }
 }
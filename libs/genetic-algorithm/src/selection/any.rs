@@ -0,0 +1,129 @@
+use crate::*;
+
+// Any of the built-in selection methods, picked at runtime - e.g. from a
+// `GeneticAlgorithmConfig` - instead of being baked into the algorithm's type
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnySelection {
+    RouletteWheel(RouletteWheelSelection),
+    Tournament(TournamentSelection),
+    Rank(RankSelection),
+    StochasticUniversal(StochasticUniversalSampling),
+}
+
+impl SelectionMethod for AnySelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        match self {
+            Self::RouletteWheel(method) => method.select(rng, population),
+            Self::Tournament(method) => method.select(rng, population),
+            Self::Rank(method) => method.select(rng, population),
+            Self::StochasticUniversal(method) => method.select(rng, population),
+        }
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        match self {
+            Self::RouletteWheel(method) => method.select_many(rng, population, count),
+            Self::Tournament(method) => method.select_many(rng, population, count),
+            Self::Rank(method) => method.select_many(rng, population, count),
+            Self::StochasticUniversal(method) => method.select_many(rng, population, count),
+        }
+    }
+
+    fn sampler<'s, 'a: 's, I>(&'s self, population: &'a [I]) -> Sampler<'s, 'a, I>
+    where
+        I: Individual,
+    {
+        match self {
+            Self::RouletteWheel(method) => method.sampler(population),
+            Self::Tournament(method) => method.sampler(population),
+            Self::Rank(method) => method.sampler(population),
+            Self::StochasticUniversal(method) => method.sampler(population),
+        }
+    }
+
+    fn selects_jointly(&self) -> bool {
+        match self {
+            Self::RouletteWheel(method) => method.selects_jointly(),
+            Self::Tournament(method) => method.selects_jointly(),
+            Self::Rank(method) => method.selects_jointly(),
+            Self::StochasticUniversal(method) => method.selects_jointly(),
+        }
+    }
+}
+
+impl From<RouletteWheelSelection> for AnySelection {
+    fn from(method: RouletteWheelSelection) -> Self {
+        Self::RouletteWheel(method)
+    }
+}
+
+impl From<TournamentSelection> for AnySelection {
+    fn from(method: TournamentSelection) -> Self {
+        Self::Tournament(method)
+    }
+}
+
+impl From<RankSelection> for AnySelection {
+    fn from(method: RankSelection) -> Self {
+        Self::Rank(method)
+    }
+}
+
+impl From<StochasticUniversalSampling> for AnySelection {
+    fn from(method: StochasticUniversalSampling) -> Self {
+        Self::StochasticUniversal(method)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn population() -> Vec<TestIndividual> {
+        (1..=10).map(|n| TestIndividual::new(n as f32)).collect()
+    }
+
+    fn selected<S: SelectionMethod>(method: &S) -> Vec<f32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+        method
+            .select_many(&mut rng, &population, 20)
+            .into_iter()
+            .map(Individual::fitness)
+            .collect()
+    }
+
+    #[test]
+    fn behaves_like_the_wrapped_method() {
+        assert_eq!(
+            selected(&AnySelection::from(RouletteWheelSelection::new())),
+            selected(&RouletteWheelSelection::new())
+        );
+        assert_eq!(
+            selected(&AnySelection::from(TournamentSelection::new(3))),
+            selected(&TournamentSelection::new(3))
+        );
+        assert_eq!(
+            selected(&AnySelection::from(RankSelection::linear(1.5))),
+            selected(&RankSelection::linear(1.5))
+        );
+        assert_eq!(
+            selected(&AnySelection::from(StochasticUniversalSampling::new())),
+            selected(&StochasticUniversalSampling::new())
+        );
+    }
+}
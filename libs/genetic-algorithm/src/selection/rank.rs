@@ -0,0 +1,198 @@
+use crate::*;
+use rand::distributions::{Distribution, WeightedIndex};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ranking {
+    // Weights grow linearly from `2 - pressure` (worst) to `pressure` (best),
+    // with pressure in [1.0, 2.0]
+    Linear { pressure: f32 },
+    // The individual ranked `n` places below the best gets weight `base^n`,
+    // with base in (0.0, 1.0]
+    Exponential { base: f32 },
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RankSelection {
+    ranking: Ranking,
+}
+
+impl RankSelection {
+    pub fn linear(pressure: f32) -> Self {
+        assert!((1.0..=2.0).contains(&pressure));
+        Self {
+            ranking: Ranking::Linear { pressure },
+        }
+    }
+
+    pub fn exponential(base: f32) -> Self {
+        assert!(base > 0.0 && base <= 1.0);
+        Self {
+            ranking: Ranking::Exponential { base },
+        }
+    }
+
+    // Indices of the population sorted from worst to best, each paired with
+    // the weight of its rank
+    fn weights<I>(&self, population: &[I]) -> (Vec<usize>, Vec<f32>)
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty(), "got an empty population");
+
+        let mut ranked: Vec<_> = (0..population.len()).collect();
+        ranked.sort_by(|&a, &b| population[a].fitness().total_cmp(&population[b].fitness()));
+
+        let last = (population.len() - 1).max(1) as f32;
+        let weights = (0..population.len())
+            .map(|rank| match self.ranking {
+                Ranking::Linear { pressure } => {
+                    (2.0 - pressure) + 2.0 * (pressure - 1.0) * (rank as f32) / last
+                }
+                Ranking::Exponential { base } => base.powi((population.len() - 1 - rank) as _),
+            })
+            .collect();
+
+        (ranked, weights)
+    }
+}
+
+impl SelectionMethod for RankSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.sampler(population)(rng)
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        let mut sample = self.sampler(population);
+        (0..count).map(|_| sample(rng)).collect()
+    }
+
+    // Ranking means sorting, so it's done once for all the picks
+    fn sampler<'s, 'a: 's, I>(&'s self, population: &'a [I]) -> Sampler<'s, 'a, I>
+    where
+        I: Individual,
+    {
+        let (ranked, weights) = self.weights(population);
+        // With linear pressure of 2.0 and a single individual every weight is 0
+        let distribution = WeightedIndex::new(&weights)
+            .unwrap_or_else(|_| WeightedIndex::new(vec![1.0; weights.len()]).unwrap());
+
+        Box::new(move |rng| &population[ranked[distribution.sample(rng)]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    fn histogram(method: RankSelection, population: &[TestIndividual]) -> BTreeMap<i32, i32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        method
+            .select_many(&mut rng, population, 1000)
+            .into_iter()
+            .fold(Default::default(), |mut histogram, individual| {
+                *histogram.entry(individual.fitness() as _).or_default() += 1;
+                histogram
+            })
+    }
+
+    // Fitness values span wildly different scales, which would make roulette
+    // wheel pick the last individual almost every time
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4000.0),
+            TestIndividual::new(3.0),
+        ]
+    }
+
+    #[test]
+    fn linear() {
+        let actual_histogram = histogram(RankSelection::linear(1.5), &population());
+
+        let expected_histogram = maplit::btreemap! {
+            // fitness => number of times this fitness was chosen
+            1 => 134,
+            2 => 193,
+            3 => 310,
+            4000 => 363,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn linear_without_pressure_is_uniform() {
+        let actual_histogram = histogram(RankSelection::linear(1.0), &population());
+
+        let expected_histogram = maplit::btreemap! {
+            // fitness => number of times this fitness was chosen
+            1 => 248,
+            2 => 253,
+            3 => 270,
+            4000 => 229,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn exponential() {
+        let actual_histogram = histogram(RankSelection::exponential(0.5), &population());
+
+        let expected_histogram = maplit::btreemap! {
+            // fitness => number of times this fitness was chosen
+            1 => 72,
+            2 => 130,
+            3 => 273,
+            4000 => 525,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn sampler_picks_like_select() {
+        let population = population();
+        let method = RankSelection::linear(1.5);
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut sample = method.sampler(&population);
+        let sampled: Vec<_> = (0..100).map(|_| sample(&mut rng).fitness()).collect();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let selected: Vec<_> = (0..100)
+            .map(|_| method.select(&mut rng, &population).fitness())
+            .collect();
+
+        assert_eq!(sampled, selected);
+    }
+
+    #[test]
+    fn single_individual() {
+        let population = vec![TestIndividual::new(0.0)];
+        let actual_histogram = histogram(RankSelection::linear(2.0), &population);
+
+        let expected_histogram = maplit::btreemap! {
+            0 => 1000,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+}
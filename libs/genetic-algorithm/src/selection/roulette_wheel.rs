@@ -1,6 +1,7 @@
 use crate::*;
 
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouletteWheelSelection;
 
 impl RouletteWheelSelection {
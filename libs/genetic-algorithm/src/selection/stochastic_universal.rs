@@ -0,0 +1,146 @@
+use crate::*;
+
+// Fitness proportional selection like `RouletteWheelSelection`, but all
+// parents of a generation are picked with a single spin of a wheel that has
+// evenly spaced pointers. Every individual is then selected within one of its
+// expected number of times, so a lucky streak can't let it dominate.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochasticUniversalSampling;
+
+impl StochasticUniversalSampling {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SelectionMethod for StochasticUniversalSampling {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty(), "got an empty population");
+
+        let total_fitness: f32 = population.iter().map(|i| i.fitness()).sum();
+        // When nobody has any fitness yet, treat everyone as equally fit
+        let fitness = |individual: &I| {
+            if total_fitness > 0.0 {
+                individual.fitness()
+            } else {
+                1.0
+            }
+        };
+        let total_fitness = population.iter().map(fitness).sum::<f32>();
+
+        let spacing = total_fitness / (count as f32);
+        let start = rng.gen::<f32>() * spacing;
+
+        let mut selected = Vec::with_capacity(count);
+        let mut individuals = population.iter();
+        let mut current = individuals.next().unwrap();
+        let mut cumulative = fitness(current);
+        for pointer in (0..count).map(|n| start + (n as f32) * spacing) {
+            while cumulative < pointer {
+                match individuals.next() {
+                    Some(next) => {
+                        current = next;
+                        cumulative += fitness(current);
+                    }
+                    // Floating point error can leave the last pointer just
+                    // past the end of the wheel
+                    None => break,
+                }
+            }
+            selected.push(current);
+        }
+
+        // Pointers visit the population in order, so shuffle to avoid always
+        // mating neighbours with each other
+        selected.shuffle(rng);
+        selected
+    }
+
+    fn selects_jointly(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    fn histogram(population: &[TestIndividual], count: usize) -> BTreeMap<i32, i32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        StochasticUniversalSampling::new()
+            .select_many(&mut rng, population, count)
+            .into_iter()
+            .fold(Default::default(), |mut histogram, individual| {
+                *histogram.entry(individual.fitness() as _).or_default() += 1;
+                histogram
+            })
+    }
+
+    #[test]
+    fn test() {
+        let population = vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ];
+
+        let actual_histogram = histogram(&population, 1000);
+
+        // Exactly proportional to fitness, unlike roulette wheel
+        let expected_histogram = maplit::btreemap! {
+            // fitness => number of times this fitness was chosen
+            1 => 100,
+            2 => 200,
+            3 => 300,
+            4 => 400,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn zero_fitness_population() {
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+        ];
+
+        let actual = StochasticUniversalSampling::new().select_many(
+            &mut ChaCha8Rng::from_seed(Default::default()),
+            &population,
+            8,
+        );
+
+        // Everyone is equally fit, so everyone gets picked exactly twice
+        for individual in &population {
+            let picked = actual
+                .iter()
+                .filter(|selected| std::ptr::eq(**selected, individual))
+                .count();
+            assert_eq!(picked, 2);
+        }
+    }
+}
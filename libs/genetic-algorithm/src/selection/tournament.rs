@@ -0,0 +1,142 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TournamentSelection {
+    // Number of individuals competing in each tournament
+    size: usize,
+    // Probability of the fittest contestant winning the tournament
+    probability: f32,
+}
+
+impl TournamentSelection {
+    pub fn new(size: usize) -> Self {
+        Self::with_probability(size, 1.0)
+    }
+
+    pub fn with_probability(size: usize, probability: f32) -> Self {
+        assert!(size > 0);
+        assert!((0.0..=1.0).contains(&probability));
+        Self { size, probability }
+    }
+}
+
+impl SelectionMethod for TournamentSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty(), "got an empty population");
+
+        // Contestants are drawn with replacement, so the tournament size is
+        // not bounded by the population size
+        let mut contestants: Vec<_> = (0..self.size)
+            .map(|_| &population[rng.gen_range(0..population.len())])
+            .collect();
+
+        if self.probability >= 1.0 {
+            return contestants
+                .into_iter()
+                .max_by(|a, b| a.fitness().total_cmp(&b.fitness()))
+                .unwrap();
+        }
+
+        // The fittest contestant wins with probability `p`, the runner-up with
+        // `p * (1 - p)` and so on; the last one takes whatever is left
+        contestants.sort_by(|a, b| b.fitness().total_cmp(&a.fitness()));
+        let last = contestants.len() - 1;
+        contestants
+            .iter()
+            .position(|_| rng.gen_bool(self.probability as _))
+            .map_or(contestants[last], |winner| contestants[winner])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    fn histogram(method: TournamentSelection, population: &[TestIndividual]) -> BTreeMap<i32, i32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        (0..1000).map(|_| method.select(&mut rng, population)).fold(
+            Default::default(),
+            |mut histogram, individual| {
+                *histogram.entry(individual.fitness() as _).or_default() += 1;
+                histogram
+            },
+        )
+    }
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ]
+    }
+
+    #[test]
+    fn deterministic_tournament() {
+        let actual_histogram = histogram(TournamentSelection::new(2), &population());
+
+        let expected_histogram = maplit::btreemap! {
+            // fitness => number of times this fitness was chosen
+            1 => 75,
+            2 => 177,
+            3 => 300,
+            4 => 448,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn probabilistic_tournament() {
+        let actual_histogram = histogram(
+            TournamentSelection::with_probability(2, 0.75),
+            &population(),
+        );
+
+        let expected_histogram = maplit::btreemap! {
+            // fitness => number of times this fitness was chosen
+            1 => 171,
+            2 => 211,
+            3 => 280,
+            4 => 338,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn single_contestant_tournament_is_uniform() {
+        let actual_histogram = histogram(TournamentSelection::new(1), &population());
+
+        let expected_histogram = maplit::btreemap! {
+            // fitness => number of times this fitness was chosen
+            1 => 265,
+            2 => 243,
+            3 => 231,
+            4 => 261,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn zero_fitness_population() {
+        let population = vec![TestIndividual::new(0.0); 4];
+        let actual_histogram = histogram(TournamentSelection::new(3), &population);
+
+        let expected_histogram = maplit::btreemap! {
+            0 => 1000,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+}
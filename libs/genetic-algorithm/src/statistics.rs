@@ -1,32 +1,75 @@
 use crate::*;
 
 #[derive(Debug, Clone)]
-pub struct Statistics {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Statistics<G = f32> {
     min_fitness: f32,
     max_fitness: f32,
     avg_fitness: f32,
+    std_dev_fitness: f32,
+    // Every fitness in the population, in ascending order
+    sorted_fitness: Vec<f32>,
+    best_index: usize,
+    best_chromosome: Chromosome<G>,
+    // Indices of the non-dominated individuals, multi-objective only
+    pareto_front: Option<Vec<usize>>,
 }
 
-impl Statistics {
+impl<G> Statistics<G>
+where
+    G: Gene,
+{
     pub fn new<I>(population: &[I]) -> Self
     where
-        I: Individual,
+        I: Individual<Gene = G>,
     {
         assert!(!population.is_empty());
 
-        let mut min_fitness = population[0].fitness();
-        let mut max_fitness = min_fitness;
+        let mut best_index = 0;
+        let mut best_fitness = population[0].fitness();
         let mut sum_fitness = 0.0;
-        for individual in population {
+        let mut sorted_fitness = Vec::with_capacity(population.len());
+        for (index, individual) in population.iter().enumerate() {
             let fitness = individual.fitness();
-            min_fitness = min_fitness.min(fitness);
-            max_fitness = max_fitness.max(fitness);
+            if fitness > best_fitness {
+                best_index = index;
+                best_fitness = fitness;
+            }
             sum_fitness += fitness;
+            sorted_fitness.push(fitness);
         }
+        sorted_fitness.sort_by(f32::total_cmp);
+
+        let avg_fitness = sum_fitness / (population.len() as f32);
+        let variance = sorted_fitness
+            .iter()
+            .map(|fitness| (fitness - avg_fitness).powi(2))
+            .sum::<f32>()
+            / (population.len() as f32);
+
+        Self {
+            min_fitness: sorted_fitness[0],
+            max_fitness: sorted_fitness[sorted_fitness.len() - 1],
+            avg_fitness,
+            std_dev_fitness: variance.sqrt(),
+            sorted_fitness,
+            best_index,
+            best_chromosome: population[best_index].chromosome().clone(),
+            pareto_front: None,
+        }
+    }
+    pub fn new_multi_objective<I>(population: &[I]) -> Self
+    where
+        I: MultiObjectiveIndividual<Gene = G>,
+    {
+        let objectives: Vec<_> = population
+            .iter()
+            .map(|individual| individual.objectives())
+            .collect();
+
         Self {
-            min_fitness,
-            max_fitness,
-            avg_fitness: sum_fitness / (population.len() as f32),
+            pareto_front: Some(pareto_front(&objectives)),
+            ..Self::new(population)
         }
     }
     pub fn min_fitness(&self) -> f32 {
@@ -38,4 +81,142 @@ impl Statistics {
     pub fn avg_fitness(&self) -> f32 {
         self.avg_fitness
     }
+    pub fn median_fitness(&self) -> f32 {
+        self.percentile(50.0)
+    }
+    // Population (not sample) standard deviation
+    pub fn std_dev_fitness(&self) -> f32 {
+        self.std_dev_fitness
+    }
+    pub fn lower_quartile(&self) -> f32 {
+        self.percentile(25.0)
+    }
+    pub fn upper_quartile(&self) -> f32 {
+        self.percentile(75.0)
+    }
+    // Fitness below which `percentile` percent of the population falls,
+    // linearly interpolated between the closest ranks
+    pub fn percentile(&self, percentile: f32) -> f32 {
+        assert!((0.0..=100.0).contains(&percentile));
+
+        let rank = percentile / 100.0 * (self.sorted_fitness.len() - 1) as f32;
+        let lower = self.sorted_fitness[rank.floor() as usize];
+        let upper = self.sorted_fitness[rank.ceil() as usize];
+        lower + (upper - lower) * rank.fract()
+    }
+    // Position of the fittest individual in the population; the first one
+    // wins when several are equally fit
+    pub fn best_index(&self) -> usize {
+        self.best_index
+    }
+    pub fn best_chromosome(&self) -> &Chromosome<G> {
+        &self.best_chromosome
+    }
+    // Positions of the individuals not dominated by anyone else; only known
+    // for statistics created via `new_multi_objective`
+    pub fn pareto_front(&self) -> Option<&[usize]> {
+        self.pareto_front.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn individual(fitness: f32) -> TestIndividual {
+        TestIndividual::create(vec![fitness].into_iter().collect())
+    }
+
+    fn statistics(fitnesses: &[f32]) -> Statistics {
+        let population: Vec<_> = fitnesses
+            .iter()
+            .map(|&fitness| individual(fitness))
+            .collect();
+        Statistics::new(&population)
+    }
+
+    #[test]
+    fn test() {
+        let stats = statistics(&[30.0, 10.0, 20.0, 50.0, 40.0]);
+
+        approx::assert_relative_eq!(stats.min_fitness(), 10.0);
+        approx::assert_relative_eq!(stats.max_fitness(), 50.0);
+        approx::assert_relative_eq!(stats.avg_fitness(), 30.0);
+        approx::assert_relative_eq!(stats.median_fitness(), 30.0);
+        approx::assert_relative_eq!(stats.std_dev_fitness(), 200.0f32.sqrt());
+        approx::assert_relative_eq!(stats.lower_quartile(), 20.0);
+        approx::assert_relative_eq!(stats.upper_quartile(), 40.0);
+        assert_eq!(stats.best_index(), 3);
+        assert_eq!(stats.best_chromosome(), individual(50.0).chromosome());
+    }
+
+    #[test]
+    fn even_population() {
+        let stats = statistics(&[4.0, 1.0, 3.0, 2.0]);
+
+        approx::assert_relative_eq!(stats.median_fitness(), 2.5);
+        approx::assert_relative_eq!(stats.lower_quartile(), 1.75);
+        approx::assert_relative_eq!(stats.upper_quartile(), 3.25);
+    }
+
+    #[test]
+    fn single_individual() {
+        let stats = statistics(&[7.0]);
+
+        approx::assert_relative_eq!(stats.median_fitness(), 7.0);
+        approx::assert_relative_eq!(stats.std_dev_fitness(), 0.0);
+        approx::assert_relative_eq!(stats.percentile(0.0), 7.0);
+        approx::assert_relative_eq!(stats.percentile(100.0), 7.0);
+        assert_eq!(stats.best_index(), 0);
+    }
+
+    #[test]
+    fn percentile() {
+        let stats = statistics(&(0..=100).map(|n| n as f32).collect::<Vec<_>>());
+
+        approx::assert_relative_eq!(stats.percentile(0.0), 0.0);
+        approx::assert_relative_eq!(stats.percentile(10.0), 10.0);
+        approx::assert_relative_eq!(stats.percentile(99.5), 99.5);
+        approx::assert_relative_eq!(stats.percentile(100.0), 100.0);
+    }
+
+    #[test]
+    fn pareto_front() {
+        let population = vec![
+            TestIndividual::create(vec![1.0, 1.0].into_iter().collect()),
+            TestIndividual::create(vec![3.0, 0.0].into_iter().collect()),
+            TestIndividual::create(vec![0.0, 0.5].into_iter().collect()),
+            TestIndividual::create(vec![0.0, 3.0].into_iter().collect()),
+        ];
+
+        assert_eq!(Statistics::new(&population).pareto_front(), None);
+
+        let mut front = Statistics::new_multi_objective(&population)
+            .pareto_front()
+            .unwrap()
+            .to_vec();
+        front.sort_unstable();
+        assert_eq!(front, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn first_best_individual_wins_ties() {
+        let stats = statistics(&[1.0, 5.0, 5.0]);
+        assert_eq!(stats.best_index(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let stats = statistics(&[30.0, 10.0, 20.0, 50.0, 40.0]);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        let actual: Statistics = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(actual.sorted_fitness, stats.sorted_fitness);
+        assert_eq!(actual.std_dev_fitness(), stats.std_dev_fitness());
+        assert_eq!(actual.best_index(), stats.best_index());
+        assert_eq!(actual.best_chromosome(), stats.best_chromosome());
+        assert_eq!(actual.pareto_front(), None);
+    }
 }
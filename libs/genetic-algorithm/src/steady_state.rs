@@ -0,0 +1,397 @@
+use crate::*;
+
+// Who makes room for the children in steady-state evolution
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Replacement {
+    // The least fit individuals
+    Worst,
+    // The individuals which have been around for the longest
+    Oldest,
+    // Individuals picked uniformly at random
+    Random,
+    // The less fit of each child's parents
+    Parent,
+    // De Jong's crowding: the individual most similar to the child out of
+    // that many picked at random, so children take over their own niche
+    // instead of someone else's
+    Crowding(usize),
+}
+
+// Steady-state evolution: instead of replacing the whole population at once,
+// every step breeds a handful of children and puts them in place of the same
+// number of existing individuals, leaving everyone else untouched
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SteadyState {
+    // Children created (and individuals replaced) per step
+    count: usize,
+    replacement: Replacement,
+    // Step at which the current occupant of each position was born
+    births: Vec<usize>,
+    step: usize,
+}
+
+impl SteadyState {
+    pub fn new(count: usize, replacement: Replacement) -> Self {
+        assert!(count > 0);
+
+        Self {
+            count,
+            replacement,
+            births: Vec::new(),
+            step: 0,
+        }
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    // Breeds children using the algorithm's operators and replaces members of
+    // `population` in place; returns the positions which got replaced along
+    // with the statistics of the population before the replacement. Every
+    // step counts as a generation of `ga`, for its hall of fame, history and
+    // observers alike.
+    pub fn evolve<S, G, I>(
+        &mut self,
+        ga: &mut GeneticAlgorithm<S, G>,
+        rng: &mut dyn RngCore,
+        population: &mut [I],
+    ) -> (Vec<usize>, Statistics<G>)
+    where
+        S: SelectionMethod,
+        G: Gene,
+        I: Individual<Gene = G>,
+    {
+        assert!(!population.is_empty());
+        assert!(
+            self.count <= population.len(),
+            "got fewer individuals than children to create"
+        );
+        let start = ga.history.is_some().then(Stopwatch::start);
+
+        // Everyone counts as equally old whenever the population is new
+        if self.births.len() != population.len() {
+            self.births = vec![self.step; population.len()];
+        }
+
+        let children = ga.breed(rng, population, self.count);
+        let slots = self.slots(rng, population, &children);
+
+        let mut parentage: Vec<_> = (0..population.len()).map(Parentage::Kept).collect();
+        for (&slot, child) in slots.iter().zip(&children) {
+            parentage[slot] = child.parentage();
+        }
+        let mut newcomers: Vec<_> = children
+            .into_iter()
+            .map(|child| I::create(child.chromosome))
+            .collect();
+        let champions = ga.reinject_champions(population, &mut newcomers);
+        for &slot in &slots[slots.len() - champions..] {
+            parentage[slot] = Parentage::Champion;
+        }
+        // Statistics (and history) describe the population before anyone
+        // gets replaced
+        let stats = ga.complete_generation(population, &parentage, start, None);
+
+        self.step += 1;
+        for (&slot, newcomer) in slots.iter().zip(newcomers) {
+            population[slot] = newcomer;
+            self.births[slot] = self.step;
+        }
+
+        (slots, stats)
+    }
+
+    // Distinct positions to put the children into
+    fn slots<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        children: &[Offspring<I::Gene>],
+    ) -> Vec<usize>
+    where
+        I: Individual,
+    {
+        let fitness = |slot: usize| population[slot].fitness();
+        let mut by_fitness: Vec<_> = (0..population.len()).collect();
+        by_fitness.sort_by(|&a, &b| fitness(a).total_cmp(&fitness(b)));
+
+        match self.replacement {
+            Replacement::Worst => by_fitness[..self.count].to_vec(),
+            Replacement::Oldest => {
+                let mut by_age: Vec<_> = (0..population.len()).collect();
+                by_age.sort_by_key(|&slot| self.births[slot]);
+                by_age.truncate(self.count);
+                by_age
+            }
+            Replacement::Random => {
+                rand::seq::index::sample(rng, population.len(), self.count).into_vec()
+            }
+            Replacement::Crowding(factor) => {
+                assert!(factor > 0);
+
+                let mut taken = vec![false; population.len()];
+                children
+                    .iter()
+                    .map(|child| {
+                        let available: Vec<_> =
+                            (0..population.len()).filter(|&slot| !taken[slot]).collect();
+                        let slot = available
+                            .choose_multiple(rng, factor)
+                            .min_by(|&&a, &&b| {
+                                let a = population[a].chromosome().distance(&child.chromosome);
+                                let b = population[b].chromosome().distance(&child.chromosome);
+                                a.total_cmp(&b)
+                            })
+                            .copied()
+                            .unwrap();
+                        taken[slot] = true;
+                        slot
+                    })
+                    .collect()
+            }
+            Replacement::Parent => {
+                // When both parents already made room for someone else, fall
+                // back to the worst individual still around
+                let mut taken = vec![false; population.len()];
+                children
+                    .iter()
+                    .map(|child| {
+                        let [a, b] = child.parents;
+                        let (worse, better) = if fitness(a) <= fitness(b) {
+                            (a, b)
+                        } else {
+                            (b, a)
+                        };
+                        let slot = [worse, better]
+                            .into_iter()
+                            .chain(by_fitness.iter().copied())
+                            .find(|&slot| !taken[slot])
+                            .unwrap();
+                        taken[slot] = true;
+                        slot
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn ga() -> GeneticAlgorithm<TournamentSelection> {
+        GeneticAlgorithm::new(
+            TournamentSelection::new(2),
+            UniformCrossover::new(),
+            GaussianMethod::new(0.5, 0.5),
+        )
+    }
+
+    fn population() -> Vec<TestIndividual> {
+        [3.0, 1.0, 4.0, 0.0, 5.0, 2.0]
+            .iter()
+            .map(|&gene| TestIndividual::create(vec![gene].into_iter().collect()))
+            .collect()
+    }
+
+    fn evolve(
+        steady_state: &mut SteadyState,
+        rng: &mut dyn RngCore,
+        population: &mut [TestIndividual],
+    ) -> Vec<usize> {
+        let (mut slots, _) = steady_state.evolve(&mut ga(), rng, population);
+        slots.sort_unstable();
+        slots
+    }
+
+    #[test]
+    fn worst() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut population = population();
+        let original = population.clone();
+
+        let slots = evolve(
+            &mut SteadyState::new(2, Replacement::Worst),
+            &mut rng,
+            &mut population,
+        );
+
+        assert_eq!(slots, vec![1, 3]);
+        for slot in [0, 2, 4, 5] {
+            assert_eq!(population[slot], original[slot]);
+        }
+    }
+
+    #[test]
+    fn oldest() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut population = population();
+        let mut steady_state = SteadyState::new(2, Replacement::Oldest);
+
+        let slots: Vec<_> = (0..3)
+            .map(|_| evolve(&mut steady_state, &mut rng, &mut population))
+            .collect();
+
+        // Everyone gets replaced exactly once before anyone's replaced twice
+        assert_eq!(slots, vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+        assert_eq!(
+            evolve(&mut steady_state, &mut rng, &mut population),
+            vec![0, 1]
+        );
+        assert_eq!(steady_state.step(), 4);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut population = population();
+
+        let slots = evolve(
+            &mut SteadyState::new(3, Replacement::Random),
+            &mut rng,
+            &mut population,
+        );
+
+        assert_eq!(slots.len(), 3);
+        assert!(slots.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn parent() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut ga = ga();
+        let population = population();
+        let steady_state = SteadyState::new(6, Replacement::Parent);
+
+        let children = ga.breed(&mut rng, &population, 6);
+        let slots = steady_state.slots(&mut rng, &population, &children);
+
+        let mut distinct = slots.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 6);
+
+        // The first child has nobody to compete with, so it replaces its
+        // less fit parent
+        let [a, b] = children[0].parents;
+        let worse = if population[a].fitness() <= population[b].fitness() {
+            a
+        } else {
+            b
+        };
+        assert_eq!(slots[0], worse);
+    }
+
+    #[test]
+    fn crowding() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+        let children = vec![
+            Offspring {
+                chromosome: vec![4.1].into_iter().collect(),
+                parents: [0, 0],
+                mutations: 0,
+            },
+            Offspring {
+                chromosome: vec![4.2].into_iter().collect(),
+                parents: [0, 0],
+                mutations: 0,
+            },
+        ];
+
+        // With everyone competing, children replace their closest lookalikes
+        let steady_state = SteadyState::new(2, Replacement::Crowding(6));
+        assert_eq!(
+            steady_state.slots(&mut rng, &population, &children),
+            vec![2, 4]
+        );
+
+        let steady_state = SteadyState::new(2, Replacement::Crowding(1));
+        let slots = steady_state.slots(&mut rng, &population, &children);
+        assert_ne!(slots[0], slots[1]);
+    }
+
+    #[test]
+    fn converges() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut ga = ga();
+        let mut population = population();
+        let mut steady_state = SteadyState::new(1, Replacement::Worst);
+
+        let initial = Statistics::new(&population).avg_fitness();
+        for _ in 0..100 {
+            steady_state.evolve(&mut ga, &mut rng, &mut population);
+        }
+
+        assert!(Statistics::new(&population).avg_fitness() > initial);
+    }
+
+    #[test]
+    fn counts_as_a_generation() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let lineage = Rc::new(RefCell::new(Lineage::new()));
+        let mut ga = ga()
+            .with_hall_of_fame(HallOfFame::new(2).with_reinjection(1))
+            .with_history()
+            .with_observer(lineage.clone());
+        let mut population = population();
+        let mut steady_state = SteadyState::new(2, Replacement::Worst);
+
+        let mut slots = Vec::new();
+        for _ in 0..3 {
+            (slots, _) = steady_state.evolve(&mut ga, &mut rng, &mut population);
+        }
+
+        assert_eq!(ga.generation(), 3);
+        assert_eq!(ga.history().unwrap().len(), 3);
+        let champion = ga
+            .hall_of_fame()
+            .unwrap()
+            .best()
+            .unwrap()
+            .chromosome()
+            .clone();
+        assert_eq!(champion, vec![5.0].into_iter().collect());
+        // The champion took the place of the last child
+        assert_eq!(population[slots[1]].chromosome(), &champion);
+        let lineage = lineage.borrow();
+        assert_eq!(lineage.len(), 3);
+        assert_eq!(lineage.generations()[2][slots[1]], Parentage::Champion);
+    }
+
+    #[test]
+    fn selection_sees_transformed_fitness() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            GaussianMethod::new(0.0, 0.0),
+        )
+        .with_fitness_transform(Underdog);
+        let mut population = population();
+
+        let (slots, _) =
+            SteadyState::new(2, Replacement::Random).evolve(&mut ga, &mut rng, &mut population);
+
+        for slot in slots {
+            assert_eq!(population[slot].chromosome()[0], 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_children() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut population = population();
+
+        SteadyState::new(7, Replacement::Worst).evolve(&mut ga(), &mut rng, &mut population);
+    }
+}
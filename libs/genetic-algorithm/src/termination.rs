@@ -0,0 +1,175 @@
+use crate::*;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+
+// Decides when an evolutionary run should stop, based on what happened so far
+pub trait TerminationCriterion<G = f32> {
+    fn should_terminate(&mut self, history: &RunHistory<G>) -> bool;
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxGenerations {
+    generations: usize,
+}
+
+impl MaxGenerations {
+    pub fn new(generations: usize) -> Self {
+        assert!(generations > 0);
+        Self { generations }
+    }
+}
+
+impl<G> TerminationCriterion<G> for MaxGenerations
+where
+    G: Gene,
+{
+    fn should_terminate(&mut self, history: &RunHistory<G>) -> bool {
+        history.len() >= self.generations
+    }
+}
+
+// Stops as soon as any generation reaches the target fitness
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetFitness {
+    target: f32,
+}
+
+impl TargetFitness {
+    pub fn new(target: f32) -> Self {
+        Self { target }
+    }
+}
+
+impl<G> TerminationCriterion<G> for TargetFitness
+where
+    G: Gene,
+{
+    fn should_terminate(&mut self, history: &RunHistory<G>) -> bool {
+        history
+            .best_so_far()
+            .is_some_and(|best| best >= self.target)
+    }
+}
+
+// Stops once the best fitness hasn't improved for the given number of
+// generations
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FitnessStagnation {
+    generations: usize,
+}
+
+impl FitnessStagnation {
+    pub fn new(generations: usize) -> Self {
+        assert!(generations > 0);
+        Self { generations }
+    }
+}
+
+impl<G> TerminationCriterion<G> for FitnessStagnation
+where
+    G: Gene,
+{
+    fn should_terminate(&mut self, history: &RunHistory<G>) -> bool {
+        history.stagnation() >= self.generations
+    }
+}
+
+// Stops once the budget has elapsed since the criterion was created. Not
+// available on wasm32-unknown-unknown, which has no clock.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
+pub struct WallClockBudget {
+    budget: Duration,
+    start: Instant,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl WallClockBudget {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            start: Instant::now(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<G> TerminationCriterion<G> for WallClockBudget {
+    fn should_terminate(&mut self, _history: &RunHistory<G>) -> bool {
+        self.start.elapsed() >= self.budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn history(max_fitnesses: &[f32]) -> RunHistory {
+        let mut history = RunHistory::new();
+        for &max_fitness in max_fitnesses {
+            let population = [TestIndividual::create(
+                vec![max_fitness].into_iter().collect(),
+            )];
+            history.record(Statistics::new(&population), None, Duration::ZERO);
+        }
+        history
+    }
+
+    mod max_generations {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut criterion = MaxGenerations::new(3);
+
+            assert!(!criterion.should_terminate(&history(&[])));
+            assert!(!criterion.should_terminate(&history(&[1.0, 1.0])));
+            assert!(criterion.should_terminate(&history(&[1.0, 1.0, 1.0])));
+        }
+    }
+
+    mod target_fitness {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut criterion = TargetFitness::new(5.0);
+
+            assert!(!criterion.should_terminate(&history(&[])));
+            assert!(!criterion.should_terminate(&history(&[1.0, 4.9])));
+            assert!(criterion.should_terminate(&history(&[1.0, 5.0])));
+            assert!(criterion.should_terminate(&history(&[6.0, 1.0])));
+        }
+    }
+
+    mod fitness_stagnation {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut criterion = FitnessStagnation::new(2);
+
+            assert!(!criterion.should_terminate(&history(&[])));
+            assert!(!criterion.should_terminate(&history(&[1.0, 2.0, 3.0])));
+            assert!(!criterion.should_terminate(&history(&[1.0, 3.0, 3.0])));
+            assert!(criterion.should_terminate(&history(&[1.0, 3.0, 3.0, 2.0])));
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    mod wall_clock_budget {
+        use super::*;
+
+        #[test]
+        fn test() {
+            assert!(WallClockBudget::new(Duration::ZERO).should_terminate(&history(&[])));
+            assert!(
+                !WallClockBudget::new(Duration::from_secs(3600)).should_terminate(&history(&[]))
+            );
+        }
+    }
+}
@@ -0,0 +1,180 @@
+pub use self::{boltzmann::*, clearing::*, linear::*, power::*, sharing::*, sigma_truncation::*};
+
+use crate::*;
+
+mod boltzmann;
+mod clearing;
+mod linear;
+mod power;
+mod sharing;
+mod sigma_truncation;
+
+// Adjusts the raw fitness of a population before the selection method gets
+// to see it; statistics, elitism and history keep using the raw fitness
+pub trait FitnessTransform {
+    // `distance(a, b)` tells how dissimilar individuals `a` and `b` are, by
+    // behaviour when both describe one and by chromosome otherwise
+    fn transform(&mut self, fitness: &mut [f32], distance: &dyn Fn(usize, usize) -> f32);
+
+    // Same as `MutationMethod::state`, for transforms changing over time
+    #[cfg(feature = "serde")]
+    fn state(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    #[cfg(feature = "serde")]
+    fn restore_state(&mut self, _state: serde_json::Value) {}
+}
+
+// Leaves nothing but the least fit individual to select from
+#[cfg(test)]
+pub struct Underdog;
+
+#[cfg(test)]
+impl FitnessTransform for Underdog {
+    fn transform(&mut self, fitness: &mut [f32], _: &dyn Fn(usize, usize) -> f32) {
+        let min = fitness.iter().copied().fold(f32::INFINITY, f32::min);
+        for fitness in fitness {
+            *fitness = if *fitness == min { 1.0 } else { 0.0 };
+        }
+    }
+}
+
+// Stand-in for an individual carrying its transformed fitness, which is all
+// the selection method gets to see
+pub(crate) struct Transformed<'a, I> {
+    pub(crate) individual: &'a I,
+    fitness: f32,
+}
+
+impl<'a, I> Transformed<'a, I>
+where
+    I: Individual,
+{
+    pub(crate) fn population(
+        transforms: &mut [Box<dyn FitnessTransform>],
+        population: &'a [I],
+    ) -> Vec<Self> {
+        let behaviours: Vec<_> = population.iter().map(Individual::behaviour).collect();
+        let distance = |a: usize, b: usize| match (&behaviours[a], &behaviours[b]) {
+            (Some(a), Some(b)) => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f32>()
+                .sqrt(),
+            _ => population[a]
+                .chromosome()
+                .distance(population[b].chromosome()),
+        };
+
+        let mut fitness: Vec<_> = population.iter().map(Individual::fitness).collect();
+        for transform in transforms {
+            transform.transform(&mut fitness, &distance);
+        }
+
+        population
+            .iter()
+            .zip(fitness)
+            .map(|(individual, fitness)| Self {
+                individual,
+                fitness,
+            })
+            .collect()
+    }
+}
+
+impl<I> Individual for Transformed<'_, I>
+where
+    I: Individual,
+{
+    type Gene = I::Gene;
+
+    fn fitness(&self) -> f32 {
+        self.fitness
+    }
+    fn chromosome(&self) -> &Chromosome<I::Gene> {
+        self.individual.chromosome()
+    }
+    fn create(_: Chromosome<I::Gene>) -> Self {
+        unreachable!("transformed individuals are only ever selected")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct Behaving(Chromosome, Vec<f32>);
+
+    impl Individual for Behaving {
+        type Gene = f32;
+
+        fn fitness(&self) -> f32 {
+            1.0
+        }
+        fn chromosome(&self) -> &Chromosome {
+            &self.0
+        }
+        fn create(chromosome: Chromosome) -> Self {
+            Self(chromosome, Vec::new())
+        }
+        fn behaviour(&self) -> Option<Vec<f32>> {
+            Some(self.1.clone())
+        }
+    }
+
+    // Doubles every fitness and records the distance between the first two
+    // individuals
+    struct Probe(Rc<Cell<f32>>);
+
+    impl FitnessTransform for Probe {
+        fn transform(&mut self, fitness: &mut [f32], distance: &dyn Fn(usize, usize) -> f32) {
+            self.0.set(distance(0, 1));
+            fitness.iter_mut().for_each(|fitness| *fitness *= 2.0);
+        }
+    }
+
+    fn probe<I: Individual>(population: &[I]) -> (f32, Vec<f32>) {
+        let distance = Rc::new(Cell::new(f32::NAN));
+        let mut transforms: Vec<Box<dyn FitnessTransform>> = vec![
+            Box::new(Probe(distance.clone())),
+            Box::new(Probe(Rc::new(Cell::new(f32::NAN)))),
+        ];
+
+        let fitness = Transformed::population(&mut transforms, population)
+            .iter()
+            .map(Individual::fitness)
+            .collect();
+
+        (distance.get(), fitness)
+    }
+
+    #[test]
+    fn genotypic_distance() {
+        let population = vec![
+            TestIndividual::create(vec![0.0, 1.0].into_iter().collect()),
+            TestIndividual::create(vec![3.0, 5.0].into_iter().collect()),
+        ];
+
+        let (distance, fitness) = probe(&population);
+
+        approx::assert_relative_eq!(distance, 5.0);
+        // Transforms are applied in order
+        assert_eq!(fitness, vec![4.0, 32.0]);
+    }
+
+    #[test]
+    fn behavioural_distance() {
+        let population = vec![
+            Behaving(vec![0.0].into_iter().collect(), vec![1.0, 1.0]),
+            Behaving(vec![0.0].into_iter().collect(), vec![4.0, 5.0]),
+        ];
+
+        let (distance, _) = probe(&population);
+
+        approx::assert_relative_eq!(distance, 5.0);
+    }
+}
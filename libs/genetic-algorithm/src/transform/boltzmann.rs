@@ -0,0 +1,107 @@
+use crate::*;
+
+// Boltzmann scaling: f' = exp(f / T). While the temperature is high every
+// individual gets a fair chance; as it cools down, selection favours the
+// fittest more and more. The temperature is multiplied by `cooling` after
+// every generation, down to `min_temperature`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoltzmannScaling {
+    temperature: f32,
+    cooling: f32,
+    min_temperature: f32,
+}
+
+impl BoltzmannScaling {
+    // Constant temperature
+    pub fn new(temperature: f32) -> Self {
+        Self::with_cooling(temperature, 1.0, temperature)
+    }
+
+    pub fn with_cooling(temperature: f32, cooling: f32, min_temperature: f32) -> Self {
+        assert!(min_temperature > 0.0);
+        assert!(temperature >= min_temperature);
+        assert!(cooling > 0.0 && cooling <= 1.0);
+
+        Self {
+            temperature,
+            cooling,
+            min_temperature,
+        }
+    }
+
+    // Temperature the next generation will be scaled with
+    pub fn temperature(&self) -> f32 {
+        self.temperature
+    }
+}
+
+impl FitnessTransform for BoltzmannScaling {
+    fn transform(&mut self, fitness: &mut [f32], _: &dyn Fn(usize, usize) -> f32) {
+        // Shifting by the maximum only scales every result by the same
+        // factor, but keeps `exp` from overflowing
+        let max = fitness.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        for fitness in fitness {
+            *fitness = ((*fitness - max) / self.temperature).exp();
+        }
+
+        self.temperature = (self.temperature * self.cooling).max(self.min_temperature);
+    }
+
+    #[cfg(feature = "serde")]
+    fn state(&self) -> Option<serde_json::Value> {
+        Some(serde_json::to_value(self).expect("couldn't save the transform state"))
+    }
+
+    #[cfg(feature = "serde")]
+    fn restore_state(&mut self, state: serde_json::Value) {
+        *self = serde_json::from_value(state).expect("got an invalid transform state");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transform(method: &mut BoltzmannScaling, fitness: &[f32]) -> Vec<f32> {
+        let mut fitness = fitness.to_vec();
+        method.transform(&mut fitness, &|_, _| 0.0);
+        fitness
+    }
+
+    #[test]
+    fn test() {
+        let actual = transform(&mut BoltzmannScaling::new(1.0), &[1.0, 2.0, 3.0]);
+
+        approx::assert_relative_eq!(actual[2], 1.0);
+        approx::assert_relative_eq!(actual[1] / actual[0], 1.0f32.exp());
+        approx::assert_relative_eq!(actual[2] / actual[1], 1.0f32.exp());
+    }
+
+    #[test]
+    fn large_fitness_does_not_overflow() {
+        let actual = transform(&mut BoltzmannScaling::new(1.0), &[1000.0, 1001.0]);
+        assert!(actual.iter().all(|fitness| fitness.is_finite()));
+    }
+
+    #[test]
+    fn cooling_increases_selection_pressure() {
+        let mut method = BoltzmannScaling::with_cooling(10.0, 0.5, 1.0);
+
+        let ratio = |actual: Vec<f32>| actual[1] / actual[0];
+        let hot = ratio(transform(&mut method, &[1.0, 2.0]));
+        approx::assert_relative_eq!(method.temperature(), 5.0);
+        let cold = ratio(transform(&mut method, &[1.0, 2.0]));
+
+        assert!(cold > hot);
+    }
+
+    #[test]
+    fn min_temperature() {
+        let mut method = BoltzmannScaling::with_cooling(4.0, 0.5, 1.5);
+        for _ in 0..5 {
+            transform(&mut method, &[1.0, 2.0]);
+        }
+        approx::assert_relative_eq!(method.temperature(), 1.5);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::*;
+
+// Clearing (Pétrowski, 1996): within every niche of `radius`, only the
+// `capacity` fittest individuals keep their fitness and everyone else's is
+// cleared to zero. Harsher than sharing, but cheaper to reason about: each
+// niche ends up contributing a fixed number of winners. Assumes non-negative
+// fitness.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Clearing {
+    radius: f32,
+    // Winners per niche
+    capacity: usize,
+}
+
+impl Clearing {
+    pub fn new(radius: f32, capacity: usize) -> Self {
+        assert!(radius > 0.0);
+        assert!(capacity > 0);
+        Self { radius, capacity }
+    }
+}
+
+impl FitnessTransform for Clearing {
+    fn transform(&mut self, fitness: &mut [f32], distance: &dyn Fn(usize, usize) -> f32) {
+        let mut order: Vec<_> = (0..fitness.len()).collect();
+        order.sort_by(|&a, &b| fitness[b].total_cmp(&fitness[a]));
+
+        // The fittest individual not cleared yet opens a new niche and
+        // everyone around it competes for the remaining places
+        for (position, &winner) in order.iter().enumerate() {
+            if fitness[winner] <= 0.0 {
+                continue;
+            }
+
+            let mut winners = 1;
+            for &other in &order[position + 1..] {
+                if fitness[other] > 0.0 && distance(winner, other) < self.radius {
+                    if winners < self.capacity {
+                        winners += 1;
+                    } else {
+                        fitness[other] = 0.0;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transform(method: &mut Clearing, positions: &[f32], fitness: &[f32]) -> Vec<f32> {
+        let mut fitness = fitness.to_vec();
+        method.transform(&mut fitness, &|a, b| (positions[a] - positions[b]).abs());
+        fitness
+    }
+
+    #[test]
+    fn keeps_best_of_each_niche() {
+        let actual = transform(
+            &mut Clearing::new(1.0, 1),
+            &[0.0, 0.5, 0.2, 10.0, 10.1],
+            &[1.0, 3.0, 2.0, 0.5, 0.7],
+        );
+        assert_eq!(actual, vec![0.0, 3.0, 0.0, 0.0, 0.7]);
+    }
+
+    #[test]
+    fn capacity() {
+        let actual = transform(
+            &mut Clearing::new(1.0, 2),
+            &[0.0, 0.5, 0.2, 10.0, 10.1],
+            &[1.0, 3.0, 2.0, 0.5, 0.7],
+        );
+        assert_eq!(actual, vec![0.0, 3.0, 2.0, 0.5, 0.7]);
+    }
+
+    #[test]
+    fn isolated_individuals_keep_their_fitness() {
+        let actual = transform(&mut Clearing::new(1.0, 1), &[0.0, 5.0], &[1.0, 2.0]);
+        assert_eq!(actual, vec![1.0, 2.0]);
+    }
+}
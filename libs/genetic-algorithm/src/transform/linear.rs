@@ -0,0 +1,92 @@
+use crate::*;
+
+// Goldberg's linear scaling: f' = a * f + b, chosen so the average fitness
+// stays the same while the best individual gets `multiple` times the
+// average. Tames super-individuals early on and exaggerates tiny differences
+// once the population has converged. Assumes non-negative fitness.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearScaling {
+    // Expected number of copies of the best individual, usually 1.2 - 2.0
+    multiple: f32,
+}
+
+impl LinearScaling {
+    pub fn new(multiple: f32) -> Self {
+        assert!(multiple >= 1.0);
+        Self { multiple }
+    }
+}
+
+impl Default for LinearScaling {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+impl FitnessTransform for LinearScaling {
+    fn transform(&mut self, fitness: &mut [f32], _: &dyn Fn(usize, usize) -> f32) {
+        if fitness.is_empty() {
+            return;
+        }
+
+        let min = fitness.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = fitness.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let avg = fitness.iter().sum::<f32>() / fitness.len() as f32;
+
+        if max - avg <= f32::EPSILON * avg.abs().max(1.0) {
+            return;
+        }
+
+        let (a, b) = if min > (self.multiple * avg - max) / (self.multiple - 1.0) {
+            let a = (self.multiple - 1.0) * avg / (max - avg);
+            (a, avg * (1.0 - a))
+        } else {
+            // Stretching that far would push the worst below zero, so scale
+            // as much as possible instead: the worst ends up at zero
+            let a = avg / (avg - min);
+            (a, -min * a)
+        };
+
+        for fitness in fitness {
+            *fitness = a * *fitness + b;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transform(multiple: f32, fitness: &[f32]) -> Vec<f32> {
+        let mut fitness = fitness.to_vec();
+        LinearScaling::new(multiple).transform(&mut fitness, &|_, _| 0.0);
+        fitness
+    }
+
+    #[test]
+    fn best_gets_multiple_of_average() {
+        let actual = transform(2.0, &[10.0, 11.0, 12.0, 15.0]);
+
+        let avg = actual.iter().sum::<f32>() / 4.0;
+        approx::assert_relative_eq!(avg, 12.0);
+        approx::assert_relative_eq!(actual[3], 24.0);
+        assert!(actual.iter().all(|&fitness| fitness >= 0.0));
+    }
+
+    #[test]
+    fn worst_is_clamped_at_zero() {
+        // Stretching the best one to twice the average would push the worst
+        // one below zero
+        let actual = transform(2.0, &[0.0, 10.0, 10.0, 10.0, 11.0]);
+
+        approx::assert_relative_eq!(actual.iter().sum::<f32>() / 5.0, 8.2);
+        approx::assert_relative_eq!(actual[0], 0.0);
+        assert!(actual[4] < 16.4);
+    }
+
+    #[test]
+    fn uniform_population() {
+        assert_eq!(transform(2.0, &[3.0, 3.0]), vec![3.0, 3.0]);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::*;
+
+// Power-law scaling: f' = f^k. Exponents above 1.0 increase the selection
+// pressure, below 1.0 decrease it. Negative fitness is treated as zero.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerScaling {
+    exponent: f32,
+}
+
+impl PowerScaling {
+    pub fn new(exponent: f32) -> Self {
+        assert!(exponent > 0.0);
+        Self { exponent }
+    }
+}
+
+impl FitnessTransform for PowerScaling {
+    fn transform(&mut self, fitness: &mut [f32], _: &dyn Fn(usize, usize) -> f32) {
+        for fitness in fitness {
+            *fitness = fitness.max(0.0).powf(self.exponent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transform(exponent: f32, fitness: &[f32]) -> Vec<f32> {
+        let mut fitness = fitness.to_vec();
+        PowerScaling::new(exponent).transform(&mut fitness, &|_, _| 0.0);
+        fitness
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(transform(2.0, &[1.0, 2.0, 3.0]), vec![1.0, 4.0, 9.0]);
+        assert_eq!(transform(0.5, &[4.0, 9.0]), vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn negative_fitness() {
+        assert_eq!(transform(2.0, &[-3.0, 1.0]), vec![0.0, 1.0]);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::*;
+
+// Fitness sharing (Goldberg & Richardson, 1987): individuals crowded within
+// `radius` of each other split their fitness, so a niche can only support as
+// many individuals as its peak is worth and distinct strategies get to
+// co-exist. Assumes non-negative fitness.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FitnessSharing {
+    // Distance below which individuals share their fitness, the niche size
+    radius: f32,
+    // Shape of the sharing function; 1.0 is triangular, higher values keep
+    // close neighbours from sharing as much
+    alpha: f32,
+}
+
+impl FitnessSharing {
+    pub fn new(radius: f32) -> Self {
+        Self::with_alpha(radius, 1.0)
+    }
+
+    pub fn with_alpha(radius: f32, alpha: f32) -> Self {
+        assert!(radius > 0.0);
+        assert!(alpha > 0.0);
+        Self { radius, alpha }
+    }
+}
+
+impl FitnessTransform for FitnessSharing {
+    fn transform(&mut self, fitness: &mut [f32], distance: &dyn Fn(usize, usize) -> f32) {
+        let niche_counts: Vec<f32> = (0..fitness.len())
+            .map(|a| {
+                (0..fitness.len())
+                    .map(|b| {
+                        let distance = if a == b { 0.0 } else { distance(a, b) };
+                        if distance < self.radius {
+                            1.0 - (distance / self.radius).powf(self.alpha)
+                        } else {
+                            0.0
+                        }
+                    })
+                    .sum()
+            })
+            .collect();
+
+        for (fitness, niche_count) in fitness.iter_mut().zip(niche_counts) {
+            *fitness /= niche_count;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Individuals on a line, at the given positions
+    fn transform(method: &mut FitnessSharing, positions: &[f32], fitness: &[f32]) -> Vec<f32> {
+        let mut fitness = fitness.to_vec();
+        method.transform(&mut fitness, &|a, b| (positions[a] - positions[b]).abs());
+        fitness
+    }
+
+    #[test]
+    fn isolated_individuals_keep_their_fitness() {
+        let actual = transform(
+            &mut FitnessSharing::new(1.0),
+            &[0.0, 5.0, 10.0],
+            &[1.0, 2.0, 3.0],
+        );
+        assert_eq!(actual, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn identical_individuals_split_their_fitness() {
+        let actual = transform(
+            &mut FitnessSharing::new(1.0),
+            &[0.0, 0.0, 0.0, 10.0],
+            &[3.0, 3.0, 3.0, 2.0],
+        );
+        // The lone individual now beats the crowded ones
+        assert_eq!(actual, vec![1.0, 1.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn sharing_falls_off_with_distance() {
+        let actual = transform(&mut FitnessSharing::new(2.0), &[0.0, 1.0], &[3.0, 3.0]);
+        // Niche count of 1 + (1 - 1/2) for both
+        approx::assert_relative_eq!(actual[0], 2.0);
+        approx::assert_relative_eq!(actual[1], 2.0);
+    }
+
+    #[test]
+    fn alpha() {
+        let actual = transform(
+            &mut FitnessSharing::with_alpha(2.0, 2.0),
+            &[0.0, 1.0],
+            &[2.5, 2.5],
+        );
+        // Niche count of 1 + (1 - (1/2)²) for both
+        approx::assert_relative_eq!(actual[0], 2.5 / 1.75);
+    }
+}
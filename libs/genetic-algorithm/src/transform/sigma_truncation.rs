@@ -0,0 +1,69 @@
+use crate::*;
+
+// Sigma truncation: f' = max(0, f - (avg - c * σ)), i.e. fitness is measured
+// relative to how the population is doing right now, and everyone more than
+// `c` standard deviations below the average drops out of selection
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SigmaTruncation {
+    // Usually between 1.0 and 3.0
+    c: f32,
+}
+
+impl SigmaTruncation {
+    pub fn new(c: f32) -> Self {
+        assert!(c >= 0.0);
+        Self { c }
+    }
+}
+
+impl Default for SigmaTruncation {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+impl FitnessTransform for SigmaTruncation {
+    fn transform(&mut self, fitness: &mut [f32], _: &dyn Fn(usize, usize) -> f32) {
+        if fitness.is_empty() {
+            return;
+        }
+
+        let len = fitness.len() as f32;
+        let avg = fitness.iter().sum::<f32>() / len;
+        let std_dev = (fitness.iter().map(|f| (f - avg).powi(2)).sum::<f32>() / len).sqrt();
+        let floor = avg - self.c * std_dev;
+
+        for fitness in fitness {
+            *fitness = (*fitness - floor).max(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transform(c: f32, fitness: &[f32]) -> Vec<f32> {
+        let mut fitness = fitness.to_vec();
+        SigmaTruncation::new(c).transform(&mut fitness, &|_, _| 0.0);
+        fitness
+    }
+
+    #[test]
+    fn test() {
+        // Average of 5, standard deviation of 2
+        let actual = transform(1.0, &[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(actual, vec![0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn offset_does_not_matter() {
+        let a = transform(2.0, &[1.0, 2.0, 3.0]);
+        let b = transform(2.0, &[1001.0, 1002.0, 1003.0]);
+
+        for (a, b) in a.iter().zip(&b) {
+            approx::assert_relative_eq!(a, b, epsilon = 1e-3);
+        }
+    }
+}
@@ -21,10 +21,15 @@ impl Simulation {
         let stats = self.sim.train(&mut self.rng);
 
         format!(
-            "min={:.2}, max={:.2}, avg={:.2}",
+            "min={:.2}, max={:.2}, avg={:.2}, median={:.2}, std={:.2}, q1={:.2}, q3={:.2}, best=#{}",
             stats.min_fitness(),
             stats.max_fitness(),
-            stats.avg_fitness()
+            stats.avg_fitness(),
+            stats.median_fitness(),
+            stats.std_dev_fitness(),
+            stats.lower_quartile(),
+            stats.upper_quartile(),
+            stats.best_index()
         )
     }
     pub fn world(&self) -> JsValue {
@@ -8,6 +8,9 @@ pub struct Animal {
     pub(crate) eye: Eye,
     pub(crate) brain: Brain,
     pub(crate) satiation: usize, // Food eaten
+    pub(crate) distance: f32,    // Distance travelled, i.e. energy spent
+    pub(crate) age: usize,       // Steps lived
+    pub(crate) turning: f32,     // Total angle turned, in either direction
 }
 
 impl Animal {
@@ -24,6 +27,9 @@ impl Animal {
             eye,
             brain,
             satiation: 0,
+            distance: 0.0,
+            age: 0,
+            turning: 0.0,
         }
     }
     pub(crate) fn as_chromosome(&self) -> ga::Chromosome {
@@ -2,13 +2,21 @@ use crate::*;
 
 pub struct AnimalIndividual {
     fitness: f32,
+    distance: f32,
+    // Average speed and turning rate, which tell e.g. circling foragers
+    // apart from straight-line ones
+    behaviour: Option<Vec<f32>>,
     chromosome: ga::Chromosome,
 }
 
 impl ga::Individual for AnimalIndividual {
+    type Gene = f32;
+
     fn create(chromosome: ga::Chromosome) -> Self {
         Self {
             fitness: 0.0,
+            distance: 0.0,
+            behaviour: None,
             chromosome,
         }
     }
@@ -18,15 +26,39 @@ impl ga::Individual for AnimalIndividual {
     fn fitness(&self) -> f32 {
         self.fitness
     }
+    fn behaviour(&self) -> Option<Vec<f32>> {
+        self.behaviour.clone()
+    }
+}
+
+// Eat as much as possible while travelling as little as possible
+impl ga::MultiObjectiveIndividual for AnimalIndividual {
+    fn objectives(&self) -> Vec<f32> {
+        vec![self.fitness, -self.distance]
+    }
 }
 
 impl AnimalIndividual {
     pub fn from_animal(animal: &Animal) -> Self {
         Self {
             fitness: animal.satiation as f32,
+            distance: animal.distance,
+            behaviour: (animal.age > 0).then(|| {
+                let age = animal.age as f32;
+                vec![animal.distance / age, animal.turning / age]
+            }),
             chromosome: animal.as_chromosome(),
         }
     }
+    // For animals born at different times: food eaten per step lived, scaled
+    // up to a whole generation so it's comparable with `from_animal`
+    pub fn from_living_animal(animal: &Animal) -> Self {
+        let rate = animal.satiation as f32 / animal.age.max(1) as f32;
+        Self {
+            fitness: rate * GENERATION_LENGTH as f32,
+            ..Self::from_animal(animal)
+        }
+    }
     pub fn into_animal(self, rng: &mut dyn RngCore) -> Animal {
         Animal::from_chromosome(self.chromosome, rng)
     }
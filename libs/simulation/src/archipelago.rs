@@ -0,0 +1,145 @@
+use crate::*;
+
+// Several worlds side by side, each evolving its animals with its own genetic
+// algorithm, whose best animals every few generations migrate to another
+// world - see `ga::IslandModel`. Worlds drifting apart keep diversity higher
+// than one big world would.
+pub struct Archipelago {
+    worlds: Vec<World>,
+    model: ga::IslandModel<ga::AnySelection>,
+    age: usize,
+}
+
+impl Archipelago {
+    pub fn random(rng: &mut dyn RngCore, islands: usize) -> Self {
+        let worlds: Vec<_> = (0..islands).map(|_| World::random(rng)).collect();
+        let island_size = worlds[0].animals.len();
+        let gas = (0..islands).map(|_| random_ga()).collect();
+        Self::new(
+            worlds,
+            ga::IslandModel::new(gas, ga::Topology::Ring, island_size),
+        )
+    }
+    // One world per island of the model, each as big as its islands
+    pub fn new(worlds: Vec<World>, model: ga::IslandModel<ga::AnySelection>) -> Self {
+        assert_eq!(
+            worlds.len(),
+            model.islands().len(),
+            "got {} worlds for {} islands",
+            worlds.len(),
+            model.islands().len()
+        );
+
+        Self {
+            worlds,
+            model,
+            age: 0,
+        }
+    }
+    pub fn worlds(&self) -> &[World] {
+        &self.worlds
+    }
+    pub fn model(&self) -> &ga::IslandModel<ga::AnySelection> {
+        &self.model
+    }
+    pub fn step(&mut self, rng: &mut dyn RngCore) -> Option<Vec<ga::Statistics>> {
+        for world in &mut self.worlds {
+            world.process_collisions(rng);
+            world.process_brains();
+            world.process_movements();
+        }
+
+        self.age += 1;
+
+        if self.age > GENERATION_LENGTH {
+            Some(self.evolve(rng))
+        } else {
+            None
+        }
+    }
+    // Statistics of every world, in order
+    pub fn train(&mut self, rng: &mut dyn RngCore) -> Vec<ga::Statistics> {
+        loop {
+            if let Some(summary) = self.step(rng) {
+                return summary;
+            }
+        }
+    }
+    pub fn evolve(&mut self, rng: &mut dyn RngCore) -> Vec<ga::Statistics> {
+        self.age = 0;
+
+        let populations: Vec<_> = self.worlds.iter().map(World::population).collect();
+        let (evolved, stats) = self.model.evolve(rng, &populations);
+
+        for (world, population) in self.worlds.iter_mut().zip(evolved) {
+            world.repopulate(rng, population);
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn world(rng: &mut dyn RngCore) -> World {
+        World {
+            animals: (0..4).map(|_| Animal::random(rng)).collect(),
+            foods: (0..6).map(|_| Food::random(rng)).collect(),
+        }
+    }
+
+    fn archipelago(rng: &mut dyn RngCore) -> Archipelago {
+        let worlds = (0..3).map(|_| world(rng)).collect();
+        let gas = (0..3).map(|_| random_ga()).collect();
+        let model = ga::IslandModel::new(gas, ga::Topology::Ring, 4).with_interval(1);
+        Archipelago::new(worlds, model)
+    }
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut archipelago = archipelago(&mut rng);
+
+        let stats = archipelago.train(&mut rng);
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(archipelago.model().generation(), 1);
+        assert!(archipelago
+            .worlds()
+            .iter()
+            .all(|world| world.animals().len() == 4));
+    }
+
+    #[test]
+    fn best_animals_migrate() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut archipelago = archipelago(&mut rng);
+        // Roulette wheel selection needs someone to have eaten something
+        for world in &mut archipelago.worlds {
+            for animal in &mut world.animals {
+                animal.satiation = 1;
+            }
+        }
+        archipelago.worlds[0].animals[2].satiation = 10;
+        let best = archipelago.worlds[0].animals[2].as_chromosome();
+
+        archipelago.evolve(&mut rng);
+
+        // In a ring, the first world sends to the second one, where migrants
+        // take the place of the last offspring
+        assert_eq!(archipelago.worlds()[1].animals()[3].as_chromosome(), best);
+    }
+
+    #[test]
+    #[should_panic]
+    fn worlds_and_islands_differ() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let worlds = (0..2).map(|_| world(&mut rng)).collect();
+        let gas = (0..3).map(|_| random_ga()).collect();
+
+        Archipelago::new(worlds, ga::IslandModel::new(gas, ga::Topology::Ring, 4));
+    }
+}
@@ -3,20 +3,29 @@ use crate::*;
 #[derive(Debug)]
 pub struct Brain {
     pub(crate) nn: nn::Network,
+    // Per-weight mutation step sizes when evolving with a self-adaptive
+    // strategy, carried along so they're inherited by the next generation
+    pub(crate) strategy: Vec<f32>,
 }
 
 impl Brain {
     pub(crate) fn random(rng: &mut dyn RngCore, eye: &Eye) -> Self {
         Self {
             nn: nn::Network::random(rng, &Self::topology(eye)),
+            strategy: Vec::new(),
         }
     }
     pub(crate) fn as_chromosome(&self) -> ga::Chromosome {
-        self.nn.weights().collect()
+        self.nn
+            .weights()
+            .collect::<ga::Chromosome>()
+            .with_strategy(self.strategy.clone())
     }
     pub(crate) fn from_chromosome(chromosome: ga::Chromosome, eye: &Eye) -> Self {
+        let strategy = chromosome.strategy().to_vec();
         Self {
             nn: nn::Network::from_weights(&Self::topology(eye), chromosome),
+            strategy,
         }
     }
     fn topology(eye: &Eye) -> [nn::LayerTopology; 3] {
@@ -31,3 +40,23 @@ impl Brain {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn chromosome_round_trip_keeps_strategy() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let eye = Eye::default();
+        let chromosome = Brain::random(&mut rng, &eye).as_chromosome();
+        let strategy: Vec<_> = (0..chromosome.len()).map(|n| n as f32).collect();
+        let chromosome = chromosome.with_strategy(strategy);
+
+        let brain = Brain::from_chromosome(chromosome.clone(), &eye);
+
+        assert_eq!(brain.as_chromosome(), chromosome);
+    }
+}
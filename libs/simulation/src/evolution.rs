@@ -0,0 +1,20 @@
+use crate::*;
+
+// How `Simulation` evolves its animals
+#[derive(Default)]
+pub enum Evolution {
+    // All of them at once with the genetic algorithm, every
+    // `GENERATION_LENGTH` steps
+    #[default]
+    Ga,
+    // By food eaten and distance travelled instead of fitness alone
+    Nsga2(ga::Nsga2),
+    // Brains sampled from CMA-ES, which has to sample as many of them as
+    // there are animals, e.g.
+    // `CmaEs::with_population_size(&mean, sigma, world.animals().len())`.
+    // Boxed, it's much bigger than the others.
+    CmaEs(Box<ga::CmaEs>),
+    // A few animals replaced with the genetic algorithm every that many
+    // steps, everyone else keeps on living
+    SteadyState(ga::SteadyState, usize),
+}
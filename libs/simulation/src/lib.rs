@@ -1,13 +1,20 @@
-pub use self::{animal::*, animal_individual::*, brain::*, eye::*, food::*, world::*};
+pub use self::{
+    animal::*, animal_individual::*, archipelago::*, brain::*, evolution::*, eye::*, food::*,
+    world::*,
+};
 use lib_genetic_algorithm as ga;
 use lib_neural_network as nn;
 use nalgebra as na;
 use rand::{Rng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::f32::consts::FRAC_PI_2;
 
 mod animal;
 mod animal_individual;
+mod archipelago;
 mod brain;
+mod evolution;
 mod eye;
 mod food;
 mod world;
@@ -19,19 +26,46 @@ const ROTATION_ACCEL: f32 = FRAC_PI_2;
 const GENERATION_LENGTH: usize = 2500;
 pub struct Simulation {
     world: World,
-    ga: ga::GeneticAlgorithm<ga::RouletteWheelSelection>,
+    ga: ga::GeneticAlgorithm<ga::AnySelection>,
+    evolution: Evolution,
     age: usize,
 }
 
 impl Simulation {
     pub fn random(rng: &mut dyn RngCore) -> Self {
         let world = World::random(rng);
-        let ga = ga::GeneticAlgorithm::new(
-            ga::RouletteWheelSelection::default(),
-            ga::UniformCrossover::default(),
-            ga::GaussianMethod::new(0.01, 0.3),
-        );
-        Self { world, ga, age: 0 }
+        Self::new(world, random_ga())
+    }
+    // Evolves the animals with whichever operators the config names, e.g.
+    // one loaded from a file to sweep over different setups
+    pub fn from_config(
+        rng: &mut dyn RngCore,
+        config: &ga::GeneticAlgorithmConfig,
+    ) -> Result<Self, ga::ConfigError> {
+        Ok(Self::new(World::random(rng), config.build()?))
+    }
+    pub fn new(world: World, ga: ga::GeneticAlgorithm<ga::AnySelection>) -> Self {
+        Self {
+            world,
+            ga,
+            evolution: Evolution::Ga,
+            age: 0,
+        }
+    }
+    pub fn with_evolution(mut self, evolution: Evolution) -> Self {
+        match &evolution {
+            Evolution::CmaEs(cma_es) => assert_eq!(
+                cma_es.population_size(),
+                self.world.animals.len(),
+                "got a CMA-ES population of {} for {} animals",
+                cma_es.population_size(),
+                self.world.animals.len()
+            ),
+            Evolution::SteadyState(_, interval) => assert!(*interval > 0),
+            Evolution::Ga | Evolution::Nsga2(_) => {}
+        }
+        self.evolution = evolution;
+        self
     }
     pub fn world(&self) -> &World {
         &self.world
@@ -43,7 +77,11 @@ impl Simulation {
 
         self.age += 1;
 
-        if self.age > GENERATION_LENGTH {
+        let due = match &self.evolution {
+            Evolution::SteadyState(_, interval) => self.age >= *interval,
+            _ => self.age > GENERATION_LENGTH,
+        };
+        if due {
             Some(self.evolve(rng))
         } else {
             None
@@ -56,61 +94,381 @@ impl Simulation {
             }
         }
     }
-    pub fn process_movements(&mut self) {
-        for animal in &mut self.world.animals {
-            animal.position += animal.rotation * na::Vector2::new(0.0, animal.speed);
-            animal.position.x = na::wrap(animal.position.x, 0.0, 1.0);
-            animal.position.y = na::wrap(animal.position.y, 0.0, 1.0);
+    // Keep training until any of the criteria fires, returning the history of
+    // every generation
+    pub fn run_until(
+        &mut self,
+        rng: &mut dyn RngCore,
+        criteria: &mut [Box<dyn ga::TerminationCriterion>],
+    ) -> ga::RunHistory {
+        assert!(!criteria.is_empty(), "got no termination criteria");
+
+        let mut history = ga::RunHistory::new();
+        loop {
+            // Brains don't change during a generation, so the population's
+            // diversity can be measured up front
+            let population: Vec<_> = self
+                .world
+                .animals()
+                .iter()
+                .map(AnimalIndividual::from_animal)
+                .collect();
+            let diversity = ga::Diversity::new(&population);
+
+            let start = ga::Stopwatch::start();
+            let stats = self.train(rng);
+            history.record(stats, Some(diversity), start.elapsed());
+
+            if criteria
+                .iter_mut()
+                .any(|criterion| criterion.should_terminate(&history))
+            {
+                return history;
+            }
         }
     }
+    pub fn process_movements(&mut self) {
+        self.world.process_movements();
+    }
     pub fn process_collisions(&mut self, rng: &mut dyn RngCore) {
-        for animal in &mut self.world.animals {
-            for food in &mut self.world.foods {
-                let distance = na::distance(&animal.position, &food.position);
-                if distance <= 0.01 {
-                    animal.satiation += 1;
-                    food.position = rng.gen();
-                }
-            }
-        }
+        self.world.process_collisions(rng);
     }
     pub fn process_brains(&mut self) {
-        for animal in &mut self.world.animals {
-            let vision =
-                animal
-                    .eye
-                    .process_vision(animal.position, animal.rotation, &self.world.foods);
-            let response = animal.brain.nn.propagate(vision);
-            let speed = response[0].clamp(-SPEED_ACCEL, SPEED_ACCEL);
-            let rotation = response[1].clamp(-ROTATION_ACCEL, ROTATION_ACCEL);
-            animal.speed = (animal.speed + speed).clamp(MIN_SPEED, MAX_SPEED);
-            animal.rotation = na::Rotation2::new(animal.rotation.angle() + rotation);
-        }
+        self.world.process_brains();
     }
+    // Breeds the next generation; steady-state evolution only replaces some
+    // of the animals, see `replace`
     pub fn evolve(&mut self, rng: &mut dyn RngCore) -> ga::Statistics {
+        if let Evolution::SteadyState(..) = self.evolution {
+            return self.replace(rng);
+        }
         self.age = 0;
 
         // Prep animals
-        let current_population: Vec<_> = self
+        let current_population = self.world.population();
+
+        // Evolve animals
+        let (evolved_population, stats) = match &mut self.evolution {
+            Evolution::Nsga2(nsga2) => nsga2.evolve(rng, &current_population),
+            Evolution::CmaEs(cma_es) => cma_es.evolve(rng, &current_population),
+            Evolution::Ga => self.ga.evolve(rng, &current_population),
+            Evolution::SteadyState(..) => unreachable!(),
+        };
+
+        // Set animals and reset food
+        self.world.repopulate(rng, evolved_population);
+        stats
+    }
+    // Steady-state counterpart of `evolve`: only the animals picked by the
+    // replacement policy are reborn, everyone else keeps on living
+    fn replace(&mut self, rng: &mut dyn RngCore) -> ga::Statistics {
+        self.age = 0;
+
+        let steady_state = match &mut self.evolution {
+            Evolution::SteadyState(steady_state, _) => steady_state,
+            _ => unreachable!(),
+        };
+        let mut population: Vec<_> = self
             .world
             .animals()
             .iter()
-            .map(AnimalIndividual::from_animal)
+            .map(AnimalIndividual::from_living_animal)
             .collect();
 
-        // Evolve animals
-        let (evolved_population, stats) = self.ga.evolve(rng, &current_population);
-
-        // Set animals
-        self.world.animals = evolved_population
-            .into_iter()
-            .map(|individual| individual.into_animal(rng))
-            .collect();
+        let (replaced, stats) = steady_state.evolve(&mut self.ga, rng, &mut population);
 
-        // Reset food
-        for food in &mut self.world.foods {
-            food.position = rng.gen();
+        let mut is_replaced = vec![false; population.len()];
+        for slot in replaced {
+            is_replaced[slot] = true;
+        }
+        for (slot, individual) in population.into_iter().enumerate() {
+            if is_replaced[slot] {
+                self.world.animals[slot] = individual.into_animal(rng);
+            }
         }
         stats
     }
 }
+
+// What `Simulation::random` and `Archipelago::random` evolve animals with
+fn random_ga() -> ga::GeneticAlgorithm<ga::AnySelection> {
+    ga::GeneticAlgorithmBuilder::new()
+        .with_selection(ga::RouletteWheelSelection::new())
+        .with_crossover(ga::UniformCrossover::new())
+        .with_mutation(ga::GaussianMethod::new(0.01, 0.3))
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    mod run_until {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            // Keep the world small, a full generation takes thousands of steps
+            let world = World {
+                animals: (0..4).map(|_| Animal::random(&mut rng)).collect(),
+                foods: (0..6).map(|_| Food::random(&mut rng)).collect(),
+            };
+            let mut simulation = Simulation::random(&mut rng);
+            simulation.world = world;
+            let mut criteria: Vec<Box<dyn ga::TerminationCriterion>> =
+                vec![Box::new(ga::MaxGenerations::new(2))];
+
+            let history = simulation.run_until(&mut rng, &mut criteria);
+
+            assert_eq!(history.len(), 2);
+            assert!(history
+                .records()
+                .iter()
+                .all(|record| record.diversity().is_some()));
+        }
+    }
+
+    mod process_collisions {
+        use super::*;
+
+        // Animals eating one after another, exactly as if nothing could be
+        // done in parallel
+        fn sequential(world: &mut World, rng: &mut dyn RngCore) {
+            for animal in &mut world.animals {
+                for food in &mut world.foods {
+                    if na::distance(&animal.position, &food.position) <= 0.01 {
+                        animal.satiation += 1;
+                        food.position = rng.gen();
+                    }
+                }
+            }
+        }
+
+        fn world(rng: &mut dyn RngCore) -> World {
+            let mut world = World {
+                animals: (0..20).map(|_| Animal::random(rng)).collect(),
+                foods: (0..500).map(|_| Food::random(rng)).collect(),
+            };
+            // A few animals sharing a spot, competing for the same food
+            for animal in &mut world.animals[..5] {
+                animal.position = na::Point2::new(0.5, 0.5);
+            }
+            world.foods[0].position = na::Point2::new(0.5, 0.5);
+            world
+        }
+
+        #[test]
+        fn same_as_sequential() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut simulation = Simulation::random(&mut rng);
+            simulation.world = world(&mut ChaCha8Rng::from_seed(Default::default()));
+            let mut expected = world(&mut ChaCha8Rng::from_seed(Default::default()));
+
+            for seed in 0..50 {
+                simulation.process_collisions(&mut ChaCha8Rng::seed_from_u64(seed));
+                sequential(&mut expected, &mut ChaCha8Rng::seed_from_u64(seed));
+                simulation.process_movements();
+                for animal in &mut expected.animals {
+                    animal.position += animal.rotation * na::Vector2::new(0.0, animal.speed);
+                    animal.position.x = na::wrap(animal.position.x, 0.0, 1.0);
+                    animal.position.y = na::wrap(animal.position.y, 0.0, 1.0);
+                }
+            }
+
+            let satiation = |world: &World| -> Vec<_> {
+                world
+                    .animals
+                    .iter()
+                    .map(|animal| animal.satiation)
+                    .collect()
+            };
+            assert_eq!(satiation(simulation.world()), satiation(&expected));
+            assert!(satiation(&expected).iter().sum::<usize>() > 1);
+            for (a, b) in simulation.world().foods().iter().zip(&expected.foods) {
+                assert_eq!(a.position(), b.position());
+            }
+        }
+    }
+
+    mod steady_state {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let world = World {
+                animals: (0..6).map(|_| Animal::random(&mut rng)).collect(),
+                foods: (0..6).map(|_| Food::random(&mut rng)).collect(),
+            };
+            let mut simulation = Simulation::random(&mut rng).with_evolution(
+                Evolution::SteadyState(ga::SteadyState::new(2, ga::Replacement::Oldest), 10),
+            );
+            simulation.world = world;
+
+            for _ in 0..9 {
+                assert!(simulation.step(&mut rng).is_none());
+            }
+            assert!(simulation.step(&mut rng).is_some());
+
+            let ages: Vec<_> = simulation
+                .world()
+                .animals()
+                .iter()
+                .map(|animal| animal.age)
+                .collect();
+            assert_eq!(ages.iter().filter(|&&age| age == 0).count(), 2);
+            assert_eq!(ages.iter().filter(|&&age| age == 10).count(), 4);
+        }
+
+        #[test]
+        fn evolve() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut simulation = Simulation::random(&mut rng).with_evolution(
+                Evolution::SteadyState(ga::SteadyState::new(2, ga::Replacement::Oldest), 10),
+            );
+            // Roulette wheel selection needs some fitness to go by
+            for animal in &mut simulation.world.animals {
+                animal.satiation = 1;
+            }
+            let before: Vec<_> = simulation
+                .world()
+                .animals()
+                .iter()
+                .map(|animal| animal.position())
+                .collect();
+
+            simulation.evolve(&mut rng);
+
+            let kept = simulation
+                .world()
+                .animals()
+                .iter()
+                .zip(&before)
+                .filter(|(animal, position)| animal.position() == **position)
+                .count();
+            assert_eq!(kept, before.len() - 2);
+        }
+    }
+
+    mod multi_objective {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let world = World {
+                animals: (0..4).map(|_| Animal::random(&mut rng)).collect(),
+                foods: (0..6).map(|_| Food::random(&mut rng)).collect(),
+            };
+            let mut simulation =
+                Simulation::random(&mut rng).with_evolution(Evolution::Nsga2(ga::Nsga2::new(
+                    ga::UniformCrossover::new(),
+                    ga::GaussianMethod::new(0.01, 0.3),
+                )));
+            simulation.world = world;
+
+            let stats = simulation.train(&mut rng);
+
+            assert!(!stats.pareto_front().unwrap().is_empty());
+            assert_eq!(simulation.world().animals().len(), 4);
+            assert!(simulation
+                .world()
+                .animals()
+                .iter()
+                .all(|animal| animal.distance == 0.0));
+        }
+    }
+
+    mod from_config {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let config = ga::GeneticAlgorithmConfig {
+                elitism: ga::Elitism::Count(1),
+                ..ga::GeneticAlgorithmConfig::new(
+                    ga::SelectionConfig::Tournament {
+                        size: 2,
+                        probability: None,
+                    },
+                    ga::CrossoverConfig::Blend { alpha: 0.5 },
+                    ga::MutationConfig::Cauchy {
+                        chance: 0.01,
+                        scale: 0.1,
+                    },
+                )
+            };
+            let world = World {
+                animals: (0..4).map(|_| Animal::random(&mut rng)).collect(),
+                foods: (0..6).map(|_| Food::random(&mut rng)).collect(),
+            };
+            let mut simulation = Simulation::from_config(&mut rng, &config).unwrap();
+            simulation.world = world;
+
+            simulation.train(&mut rng);
+
+            assert_eq!(simulation.world().animals().len(), 4);
+        }
+    }
+
+    mod cma_es {
+        use super::*;
+
+        fn cma_es(simulation: &Simulation) -> &ga::CmaEs {
+            match &simulation.evolution {
+                Evolution::CmaEs(cma_es) => cma_es,
+                _ => unreachable!(),
+            }
+        }
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let world = World {
+                animals: (0..4).map(|_| Animal::random(&mut rng)).collect(),
+                foods: (0..6).map(|_| Food::random(&mut rng)).collect(),
+            };
+            let mean = world.animals[0].as_chromosome();
+            let mut simulation = Simulation::random(&mut rng);
+            simulation.world = world;
+            let mut simulation = simulation.with_evolution(Evolution::CmaEs(Box::new(
+                ga::CmaEs::with_population_size(&mean, 0.5, 4),
+            )));
+
+            simulation.train(&mut rng);
+            simulation.train(&mut rng);
+
+            assert_eq!(cma_es(&simulation).generation(), 2);
+            assert_eq!(simulation.world().animals().len(), 4);
+        }
+
+        #[test]
+        fn sized_to_the_world() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let simulation = Simulation::random(&mut rng);
+            let animals = simulation.world().animals().len();
+            let mean = simulation.world().animals()[0].as_chromosome();
+
+            let simulation = simulation.with_evolution(Evolution::CmaEs(Box::new(
+                ga::CmaEs::with_population_size(&mean, 0.5, animals),
+            )));
+
+            assert_eq!(cma_es(&simulation).population_size(), animals);
+        }
+
+        #[test]
+        #[should_panic]
+        fn wrong_population_size() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let simulation = Simulation::random(&mut rng);
+            let mean = simulation.world().animals()[0].as_chromosome();
+
+            // 4 + 3 ln n samples for this many weights, not one per animal
+            simulation.with_evolution(Evolution::CmaEs(Box::new(ga::CmaEs::new(&mean, 0.5))));
+        }
+    }
+}
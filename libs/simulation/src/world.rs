@@ -19,4 +19,90 @@ impl World {
     pub fn foods(&self) -> &[Food] {
         &self.foods
     }
+    pub(crate) fn process_movements(&mut self) {
+        for animal in &mut self.animals {
+            animal.position += animal.rotation * na::Vector2::new(0.0, animal.speed);
+            animal.distance += animal.speed;
+            animal.age += 1;
+            animal.position.x = na::wrap(animal.position.x, 0.0, 1.0);
+            animal.position.y = na::wrap(animal.position.y, 0.0, 1.0);
+        }
+    }
+    pub(crate) fn process_collisions(&mut self, rng: &mut dyn RngCore) {
+        // Looking for food within reach is the expensive part and can be done
+        // for every animal at once. Eating has to happen one animal after
+        // another though, since eaten food reappears elsewhere right away.
+        let foods = &self.foods;
+        let within_reach = |animal: &Animal| -> Vec<usize> {
+            (0..foods.len())
+                .filter(|&food| na::distance(&animal.position, &foods[food].position) <= 0.01)
+                .collect()
+        };
+        #[cfg(feature = "parallel")]
+        let within_reach: Vec<_> = self.animals.par_iter().map(within_reach).collect();
+        #[cfg(not(feature = "parallel"))]
+        let within_reach: Vec<_> = self.animals.iter().map(within_reach).collect();
+
+        // Food which has already reappeared has to be checked again
+        let mut moved = vec![false; self.foods.len()];
+        let mut moved_foods = Vec::new();
+        for (animal, within_reach) in self.animals.iter_mut().zip(within_reach) {
+            let mut candidates: Vec<_> = within_reach
+                .into_iter()
+                .chain(moved_foods.clone())
+                .collect();
+            candidates.sort_unstable();
+            candidates.dedup();
+
+            for index in candidates {
+                let food = &mut self.foods[index];
+                if moved[index] && na::distance(&animal.position, &food.position) > 0.01 {
+                    continue;
+                }
+                animal.satiation += 1;
+                food.position = rng.gen();
+                if !moved[index] {
+                    moved[index] = true;
+                    moved_foods.push(index);
+                }
+            }
+        }
+    }
+    pub(crate) fn process_brains(&mut self) {
+        let foods = &self.foods;
+        let think = |animal: &mut Animal| {
+            let vision = animal
+                .eye
+                .process_vision(animal.position, animal.rotation, foods);
+            let response = animal.brain.nn.propagate(vision);
+            let speed = response[0].clamp(-SPEED_ACCEL, SPEED_ACCEL);
+            let rotation = response[1].clamp(-ROTATION_ACCEL, ROTATION_ACCEL);
+            animal.speed = (animal.speed + speed).clamp(MIN_SPEED, MAX_SPEED);
+            animal.rotation = na::Rotation2::new(animal.rotation.angle() + rotation);
+            animal.turning += rotation.abs();
+        };
+        #[cfg(feature = "parallel")]
+        self.animals.par_iter_mut().for_each(think);
+        #[cfg(not(feature = "parallel"))]
+        self.animals.iter_mut().for_each(think);
+    }
+    // Every animal as an individual to evolve
+    pub(crate) fn population(&self) -> Vec<AnimalIndividual> {
+        self.animals
+            .iter()
+            .map(AnimalIndividual::from_animal)
+            .collect()
+    }
+    // Replaces every animal with one born from the evolved population and
+    // scatters the food anew
+    pub(crate) fn repopulate(&mut self, rng: &mut dyn RngCore, population: Vec<AnimalIndividual>) {
+        self.animals = population
+            .into_iter()
+            .map(|individual| individual.into_animal(rng))
+            .collect();
+
+        for food in &mut self.foods {
+            food.position = rng.gen();
+        }
+    }
 }